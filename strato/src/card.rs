@@ -1,8 +1,11 @@
+use std::collections::{BTreeMap, HashMap};
+
 use anyhow::Result;
 use rand::Rng;
 use thiserror::Error;
 
 #[derive(PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card {
     value: CardValue,
     flipped: bool,
@@ -24,10 +27,20 @@ impl Card {
         }
     }
 
+    /// The card's value, whether or not it has been flipped.
+    pub(crate) fn value(&self) -> CardValue {
+        self.value
+    }
+
     pub fn flip(&mut self) {
         self.flipped = true;
     }
 
+    /// Turn the card face-down again, e.g. when the discard pile is shuffled back into the deck.
+    pub fn unflip(&mut self) {
+        self.flipped = false;
+    }
+
     pub fn is_flipped(&self) -> bool {
         self.flipped
     }
@@ -48,7 +61,7 @@ impl std::fmt::Debug for Card {
     }
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub enum CardValue {
     NegativeTwo,
     NegativeOne,
@@ -116,13 +129,58 @@ impl From<CardValue> for i32 {
     }
 }
 
+/// Card values are stored as the number printed on the card.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CardValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(i32::from(*self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CardValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <i32 as serde::Deserialize>::deserialize(deserializer)?;
+        if (-2..=12).contains(&value) {
+            Ok(CardValue::from(value))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Signed(value.into()),
+                &"a card value from -2 to 12",
+            ))
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Deck(Vec<Card>);
 
 impl Deck {
     pub const EMPTY_SIZE: usize = 0;
+    /// The size of one standard deck.
     pub const FULL_SIZE: usize = 150;
 
+    /// Create a deck with a custom mix of cards, repeated `sets` times for large tables.
+    pub fn with_composition(composition: &DeckComposition, sets: usize) -> Self {
+        let mut remaining = composition
+            .0
+            .iter()
+            .map(|(value, count)| (*value, count * sets))
+            .collect::<Vec<_>>();
+
+        // Lay the cards out in runs of ascending value, like a fresh pack.
+        let mut cards = vec![];
+        while remaining.iter().any(|(_, count)| *count > 0) {
+            for (value, count) in remaining.iter_mut().filter(|(_, count)| *count > 0) {
+                cards.push(Card::new(i32::from(*value)));
+                *count -= 1;
+            }
+        }
+
+        Self(cards)
+    }
+
     pub fn size(&self) -> usize {
         self.0.len()
     }
@@ -130,7 +188,14 @@ impl Deck {
     /// Mimic human shuffle by splitting (sort of) in half and then zipping together (imperfectly), repeated
     /// a loose number of times. Then do some swaps until it feels right. 😄
     pub fn shuffle(&mut self) {
-        let mut rng = rand::thread_rng();
+        self.shuffle_with(&mut rand::thread_rng());
+    }
+
+    /// Shuffle using the given source of randomness, so the same seed always gives the same order.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        if self.size() < 2 {
+            return;
+        }
 
         let times_to_shuffle = rng.gen_range(4..=7);
         let middle = self.size() / 2;
@@ -139,7 +204,7 @@ impl Deck {
         let mut left_hand = self.0.clone();
 
         for _ in 0..times_to_shuffle {
-            let variance_from_middle = rng.gen_range(0..max_variance_from_middle);
+            let variance_from_middle = rng.gen_range(0..=max_variance_from_middle);
             let guess_at_middle = if rng.gen_bool(1.0 / 2.0) {
                 middle.checked_add(variance_from_middle).unwrap_or(middle)
             } else {
@@ -187,20 +252,80 @@ impl Deck {
     pub fn draw(&mut self) -> Option<Card> {
         self.0.pop()
     }
+
+    pub fn cards(&self) -> impl Iterator<Item = &Card> {
+        self.0.iter()
+    }
+
+    /// Put cards on the bottom of the deck, face-down.
+    pub fn refill(&mut self, cards: Vec<Card>) {
+        let mut cards = cards;
+        cards.iter_mut().for_each(Card::unflip);
+        cards.append(&mut self.0);
+        self.0 = cards;
+    }
 }
 
 impl Default for Deck {
-    /// Create a new deck which consists of ten full sets of -2 through 12.
+    /// Create a standard deck, which consists of ten runs of -2 through 12.
     fn default() -> Self {
-        let cards = (0..10)
-            .map(|_| (-2..=12).map(|n| Card::new(n)).collect::<Vec<_>>())
-            .flatten()
-            .collect::<Vec<_>>();
-        Self(cards)
+        Self::with_composition(&DeckComposition::default(), 1)
+    }
+}
+
+/// How many cards of each value make up a deck, for house rules.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeckComposition(BTreeMap<CardValue, usize>);
+
+impl DeckComposition {
+    /// No cards at all, to build a custom mix from.
+    pub fn empty() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Ten of every value from -2 through 12. This is the default.
+    pub fn uniform() -> Self {
+        (-2..=12).fold(Self::empty(), |composition, value| {
+            composition.with(CardValue::from(value), 10)
+        })
+    }
+
+    /// The mix in the physical game: 5 × -2, 15 × 0 and 10 of every other value.
+    pub fn official() -> Self {
+        Self::uniform()
+            .with(CardValue::NegativeTwo, 5)
+            .with(CardValue::Zero, 15)
+    }
+
+    /// Set how many cards of a value are in the deck.
+    pub fn with(mut self, value: CardValue, count: usize) -> Self {
+        if count == 0 {
+            self.0.remove(&value);
+        } else {
+            self.0.insert(value, count);
+        }
+        self
+    }
+
+    pub fn count(&self, value: CardValue) -> usize {
+        self.0.get(&value).copied().unwrap_or(0)
+    }
+
+    /// How many cards there are in total.
+    pub fn size(&self) -> usize {
+        self.0.values().sum()
+    }
+}
+
+impl Default for DeckComposition {
+    fn default() -> Self {
+        Self::uniform()
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiscardPile(Vec<Card>);
 
 impl DiscardPile {
@@ -218,10 +343,39 @@ impl DiscardPile {
         self.0.pop()
     }
 
-    /// Put a card on the top of the discard pile.
-    pub fn put(&mut self, card: Card) {
+    pub fn cards(&self) -> impl Iterator<Item = &Card> {
+        self.0.iter()
+    }
+
+    /// Look at the top card without taking it.
+    pub fn top(&self) -> Option<&Card> {
+        self.0.last()
+    }
+
+    /// Put a card on the top of the discard pile. Discarded cards are always face-up.
+    pub fn put(&mut self, mut card: Card) {
+        card.flip();
         self.0.push(card)
     }
+
+    /// Take every card except the top one, leaving it as the only card in the pile.
+    pub fn take_all_but_top(&mut self) -> Vec<Card> {
+        match self.0.pop() {
+            Some(top_card) => std::mem::replace(&mut self.0, vec![top_card]),
+            None => vec![],
+        }
+    }
+}
+
+/// How many cards there are of each value, whether they are face-up or not.
+pub(crate) fn count_values<'a>(
+    cards: impl IntoIterator<Item = &'a Card>,
+) -> HashMap<CardValue, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(card.value).or_insert(0) += 1;
+    }
+    counts
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -238,20 +392,107 @@ pub enum SpreadActionError {
     CardAlreadyFlipped,
 }
 
-type FourColumns = [Option<Card>; 4];
-type ThreeByFourGrid = [FourColumns; 3];
+/// What can be seen at one spot of a spread.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Spot {
+    /// The card was removed along with its column.
+    Empty,
+    FaceDown,
+    FaceUp(CardValue),
+}
+
+impl From<&Option<Card>> for Spot {
+    fn from(place: &Option<Card>) -> Self {
+        match place {
+            None => Spot::Empty,
+            Some(card) => match card.get_value() {
+                Some(value) => Spot::FaceUp(value),
+                None => Spot::FaceDown,
+            },
+        }
+    }
+}
+
+/// How many rows and columns of cards each player is dealt.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpreadSize {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl SpreadSize {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self { rows, columns }
+    }
+
+    /// How many cards fill a spread of this size.
+    pub fn cards(&self) -> usize {
+        self.rows * self.columns
+    }
+}
+
+impl Default for SpreadSize {
+    /// The standard spread of three rows and four columns.
+    fn default() -> Self {
+        Self::new(3, 4)
+    }
+}
+
+/// A straight line of spots in a spread, which is cleared when its cards match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Line {
+    Row(usize),
+    Column(usize),
+    /// Runs from the top row to the bottom row, starting at `column` and moving one column
+    /// to the right (or to the left) on each row.
+    Diagonal {
+        column: usize,
+        rightwards: bool,
+    },
+}
+
+/// Which lines of matching cards are cleared from a spread.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchLines {
+    #[default]
+    Columns,
+    ColumnsAndRows,
+    ColumnsRowsAndDiagonals,
+}
+
+/// The house rule for clearing matching cards.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchRule {
+    pub lines: MatchLines,
+    /// A line of -2s stays in the spread, since it's worth more there.
+    pub keep_negative_twos: bool,
+}
+
+type Grid = Vec<Vec<Option<Card>>>;
 
-#[derive(Default, Clone, PartialEq)]
-pub struct PlayerSpread(ThreeByFourGrid);
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerSpread(Grid);
 
 impl PlayerSpread {
-    /// Create a new deck which consists of ten full sets of -2 through 12.
+    /// Create an empty spread of the standard size.
     pub fn new() -> Self {
-        Self([
-            [None, None, None, None],
-            [None, None, None, None],
-            [None, None, None, None],
-        ])
+        Self::with_size(SpreadSize::default())
+    }
+
+    /// Create an empty spread with any number of rows and columns.
+    pub fn with_size(size: SpreadSize) -> Self {
+        Self(vec![vec![None; size.columns]; size.rows])
+    }
+
+    /// The number of rows and columns the spread was dealt with, including cleared columns.
+    pub fn size(&self) -> SpreadSize {
+        SpreadSize::new(self.0.len(), self.0.first().map_or(0, Vec::len))
     }
 
     pub fn view(&self) -> Vec<Vec<Option<CardValue>>> {
@@ -273,6 +514,14 @@ impl PlayerSpread {
             .collect::<Vec<_>>()
     }
 
+    /// Like `view`, but tells apart the spots whose card is face-down from the empty ones.
+    pub fn spots(&self) -> Vec<Vec<Spot>> {
+        self.0
+            .iter()
+            .map(|row| row.iter().map(Spot::from).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    }
+
     /// Take a card from a specified row and column.
     pub fn take_from(&mut self, row: usize, column: usize) -> Result<Card, SpreadActionError> {
         self.0
@@ -299,15 +548,15 @@ impl PlayerSpread {
             .ok_or(SpreadActionError::ColumnDoesntExist("place"))?;
 
         if place.is_some() {
-            return Err(SpreadActionError::SpotTaken);
+            Err(SpreadActionError::SpotTaken)
         } else {
             place.replace(card);
             Ok(())
         }
     }
 
-    /// Flip a card at a specified row and column.
-    pub fn flip_at(&mut self, row: usize, column: usize) -> Result<(), SpreadActionError> {
+    /// Flip a card at a specified row and column, revealing its value.
+    pub fn flip_at(&mut self, row: usize, column: usize) -> Result<CardValue, SpreadActionError> {
         // Validates that row and column fit within bounds
         let selected_card = self
             .0
@@ -319,62 +568,143 @@ impl PlayerSpread {
             .ok_or(SpreadActionError::NoCardFound)?;
 
         if selected_card.is_flipped() {
-            return Err(SpreadActionError::CardAlreadyFlipped);
+            Err(SpreadActionError::CardAlreadyFlipped)
         } else {
             selected_card.flip();
-            Ok(())
+            Ok(selected_card.value())
         }
     }
 
+    /// Whether every row has exactly as many spots as the size says.
+    pub(crate) fn has_size(&self, size: SpreadSize) -> bool {
+        self.0.len() == size.rows && self.0.iter().all(|row| row.len() == size.columns)
+    }
+
     /// Determine number of active columns.
     pub fn active_columns(&self) -> usize {
-        self.0
-            .get(0)
-            .unwrap()
-            .iter()
-            .filter(|c| c.is_some())
-            .collect::<Vec<_>>()
-            .len()
+        (0..self.size().columns)
+            .filter(|column| {
+                self.0
+                    .iter()
+                    .any(|row| row.get(*column).is_some_and(Option::is_some))
+            })
+            .count()
     }
 
-    /// If the column has matching cards, remove it.
-    // TODO: Write tests for this
-    pub fn remove_column_if_matches(&mut self, column: usize) -> Result<(), SpreadActionError> {
-        let values = self
-            .0
-            .iter()
-            .map(|row| row.get(column))
-            .flatten()
+    /// If the column has matching cards, remove it. Returns the removed cards, if any.
+    /// Matches the same way as `clear_matches_at` with the default `MatchRule`.
+    pub fn remove_column_if_matches(
+        &mut self,
+        column: usize,
+    ) -> Result<Vec<Card>, SpreadActionError> {
+        // Only the column is checked under the default rule, so any row will do.
+        Ok(self
+            .clear_matches_at(0, column, MatchRule::default())
+            .into_iter()
+            .flat_map(|(_, cards)| cards)
+            .collect())
+    }
+
+    /// Clear every line through the spot that the rule allows and whose cards match: at least
+    /// two cards left, all face-up and of the same value. Returns the cleared lines and their cards.
+    pub fn clear_matches_at(
+        &mut self,
+        row: usize,
+        column: usize,
+        rule: MatchRule,
+    ) -> Vec<(Line, Vec<Card>)> {
+        let matching = self
+            .lines_through(row, column, rule.lines)
+            .into_iter()
+            .filter(|line| self.line_matches(*line, rule))
             .collect::<Vec<_>>();
 
-        // If any of the values are None, then the column is not full.
-        if values.iter().any(|c| c.is_none()) {
-            return Ok(());
+        // A card shared by two cleared lines is reported with the first one.
+        matching
+            .into_iter()
+            .map(|line| {
+                let cards = self
+                    .spots_on(line)
+                    .into_iter()
+                    .filter_map(|(row, column)| self.0[row][column].take())
+                    .collect();
+                (line, cards)
+            })
+            .collect()
+    }
+
+    fn lines_through(&self, row: usize, column: usize, lines: MatchLines) -> Vec<Line> {
+        let mut through = vec![Line::Column(column)];
+        if lines != MatchLines::Columns {
+            through.push(Line::Row(row));
         }
-        // If any of the values are not flipped, then the column is not ready.
-        if values.iter().any(|c| !c.unwrap().is_flipped()) {
-            return Ok(());
+        if lines == MatchLines::ColumnsRowsAndDiagonals {
+            let rightwards = Line::Diagonal {
+                column: column.wrapping_sub(row),
+                rightwards: true,
+            };
+            let leftwards = Line::Diagonal {
+                column: column + row,
+                rightwards: false,
+            };
+            through.extend(
+                [rightwards, leftwards]
+                    .into_iter()
+                    .filter(|line| !self.spots_on(*line).is_empty()),
+            );
         }
+        through
+    }
 
-        let first_value = values.iter().next().unwrap().unwrap();
-        let column_matches = values.iter().all(|c| c.unwrap() == first_value);
+    /// Every spot on the line, or nothing if the line doesn't fit in the spread.
+    fn spots_on(&self, line: Line) -> Vec<(usize, usize)> {
+        let size = self.size();
+        let spots = match line {
+            Line::Row(row) => (0..size.columns).map(|column| (row, column)).collect(),
+            Line::Column(column) => (0..size.rows).map(|row| (row, column)).collect(),
+            Line::Diagonal { column, rightwards } => (0..size.rows)
+                .map(|row| {
+                    let column = if rightwards {
+                        column.checked_add(row)
+                    } else {
+                        column.checked_sub(row)
+                    };
+                    column.map(|column| (row, column))
+                })
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_default(),
+        };
 
-        if column_matches {
-            // Remove column
-            for row in self.0.iter_mut() {
-                row[column] = None;
-            }
+        if spots
+            .iter()
+            .all(|(row, column)| *row < size.rows && *column < size.columns)
+        {
+            spots
+        } else {
+            vec![]
+        }
+    }
+
+    fn line_matches(&self, line: Line, rule: MatchRule) -> bool {
+        let cards = self
+            .spots_on(line)
+            .into_iter()
+            .filter_map(|(row, column)| self.0[row][column])
+            .collect::<Vec<_>>();
+
+        if cards.len() < 2 || cards.iter().any(|c| !c.is_flipped()) {
+            return false;
         }
 
-        Ok(())
+        let value = cards[0].value;
+        if rule.keep_negative_twos && value == CardValue::NegativeTwo {
+            return false;
+        }
+        cards.iter().all(|c| c.value == value)
     }
 
     pub fn remaining_cards(&self) -> impl Iterator<Item = &Card> {
-        self.0
-            .iter()
-            .flatten()
-            .filter(|c| c.is_some())
-            .map(|c| c.as_ref().unwrap())
+        self.0.iter().flatten().filter_map(|c| c.as_ref())
     }
 
     pub fn flipped_cards(&self) -> usize {
@@ -405,6 +735,12 @@ impl PlayerSpread {
     }
 }
 
+impl Default for PlayerSpread {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl std::fmt::Debug for PlayerSpread {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let cards = self
@@ -520,6 +856,35 @@ mod tests {
         assert_ne!(deck, snapshot);
     }
 
+    #[test]
+    fn seeded_shuffles_are_repeatable() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let mut deck = Deck::default();
+        let mut same_seed = Deck::default();
+        let mut other_seed = Deck::default();
+
+        deck.shuffle_with(&mut ChaCha8Rng::seed_from_u64(7));
+        same_seed.shuffle_with(&mut ChaCha8Rng::seed_from_u64(7));
+        other_seed.shuffle_with(&mut ChaCha8Rng::seed_from_u64(8));
+
+        assert_eq!(deck, same_seed);
+        assert_ne!(deck, other_seed);
+    }
+
+    #[test]
+    fn tiny_deck_can_be_shuffled() {
+        let mut deck = Deck::default();
+
+        for _ in 0..(deck.size() - 2) {
+            deck.draw();
+        }
+
+        deck.shuffle();
+        assert_eq!(deck.size(), 2);
+    }
+
     #[test]
     fn small_deck_can_be_shuffled() {
         let mut deck = Deck::default();
@@ -534,6 +899,109 @@ mod tests {
         assert_ne!(deck, snapshot);
     }
 
+    #[test]
+    fn a_card_can_be_unflipped() {
+        let mut card = Card::new(7);
+        card.flip();
+        card.unflip();
+        assert!(!card.is_flipped());
+        assert_eq!(card.get_value(), None);
+    }
+
+    #[test]
+    fn a_deck_can_be_refilled_face_down() {
+        let mut deck = Deck::default();
+        while deck.draw().is_some() {}
+
+        let mut three = Card::new(3);
+        three.flip();
+        deck.refill(vec![three, Card::new(8)]);
+
+        assert_eq!(deck.size(), 2);
+        assert_eq!(deck.draw(), Some(Card::new(8)));
+        assert_eq!(deck.draw(), Some(Card::new(3)));
+    }
+
+    #[test]
+    fn refilling_puts_cards_under_the_deck() {
+        let mut deck = Deck::default();
+        deck.refill(vec![Card::new(4)]);
+        assert_eq!(deck.size(), 151);
+        assert_eq!(deck.draw(), Some(Card::new(12)));
+    }
+
+    #[test]
+    fn decks_can_be_combined_for_big_tables() {
+        let composition = DeckComposition::default();
+        let deck = Deck::with_composition(&composition, 3);
+        assert_eq!(deck.size(), composition.size() * 3);
+
+        let counts = count_values(deck.cards());
+        assert_eq!(counts.len(), 15);
+        assert!(counts.values().all(|count| *count == 30));
+    }
+
+    #[test]
+    fn the_official_deck_has_fewer_negative_twos() {
+        let composition = DeckComposition::official();
+        assert_eq!(composition.size(), Deck::FULL_SIZE);
+
+        let counts = count_values(Deck::with_composition(&composition, 1).cards());
+        assert_eq!(counts[&CardValue::NegativeTwo], 5);
+        assert_eq!(counts[&CardValue::Zero], 15);
+        assert_eq!(counts[&CardValue::Twelve], 10);
+    }
+
+    #[test]
+    fn a_custom_deck_only_has_the_cards_asked_for() {
+        let composition = DeckComposition::empty()
+            .with(CardValue::Five, 3)
+            .with(CardValue::Twelve, 2)
+            .with(CardValue::Twelve, 0);
+        assert_eq!(composition.size(), 3);
+
+        let mut deck = Deck::with_composition(&composition, 2);
+        assert_eq!(deck.size(), 6);
+        while let Some(mut card) = deck.draw() {
+            card.flip();
+            assert_eq!(card.get_value(), Some(CardValue::Five));
+        }
+    }
+
+    #[test]
+    fn discard_pile_keeps_its_top_card() {
+        let mut pile = DiscardPile::new();
+        assert!(pile.take_all_but_top().is_empty());
+
+        pile.put(Card::new(1));
+        pile.put(Card::new(2));
+        pile.put(Card::new(3));
+
+        let taken = pile.take_all_but_top();
+        let taken_values = taken.iter().map(Card::get_value).collect::<Vec<_>>();
+        assert_eq!(
+            taken_values,
+            vec![Some(CardValue::One), Some(CardValue::Two)]
+        );
+        assert_eq!(pile.size(), 1);
+        assert_eq!(pile.take().unwrap().get_value(), Some(CardValue::Three));
+    }
+
+    #[test]
+    fn a_player_spread_shows_what_is_at_each_spot() {
+        let mut spread = PlayerSpread::new();
+        let mut four = Card::new(4);
+        four.flip();
+        spread.place_at(four, 0, 0).unwrap();
+        spread.place_at(Card::new(9), 0, 1).unwrap();
+
+        let spots = spread.spots();
+        assert_eq!(spots.len(), 3);
+        assert_eq!(spots[0][0], Spot::FaceUp(CardValue::Four));
+        assert_eq!(spots[0][1], Spot::FaceDown);
+        assert_eq!(spots[0][2], Spot::Empty);
+    }
+
     fn init_player_spread() -> PlayerSpread {
         let mut deck = Deck::default();
         deck.shuffle();
@@ -550,6 +1018,121 @@ mod tests {
         spread
     }
 
+    #[test]
+    fn a_spread_can_be_any_size() {
+        let size = SpreadSize::new(4, 2);
+        let mut spread = PlayerSpread::with_size(size);
+        assert_eq!(spread.size(), size);
+        assert_eq!(spread.active_columns(), 0);
+
+        for row in 0..4 {
+            spread.place_at(Card::new(3), row, 0).unwrap();
+            spread.place_at(Card::new(-1), row, 1).unwrap();
+            spread.flip_at(row, 1).unwrap();
+        }
+        assert_eq!(spread.active_columns(), 2);
+        assert_eq!(spread.score(), -4);
+        assert_eq!(
+            spread.place_at(Card::new(3), 0, 2),
+            Err(SpreadActionError::ColumnDoesntExist("place"))
+        );
+
+        let removed = spread.remove_column_if_matches(1).unwrap();
+        assert_eq!(removed.len(), 4);
+        assert_eq!(spread.active_columns(), 1);
+        assert_eq!(format!("{spread:?}").lines().count(), 5);
+    }
+
+    /// A 3×4 spread with every card face-up, laid out from the given values.
+    fn spread_of(values: [[i32; 4]; 3]) -> PlayerSpread {
+        let mut spread = PlayerSpread::new();
+        for (row, values) in values.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                let mut card = Card::new(*value);
+                card.flip();
+                spread.place_at(card, row, column).unwrap();
+            }
+        }
+        spread
+    }
+
+    #[test]
+    fn only_columns_are_cleared_by_default() {
+        let mut spread = spread_of([[4, 4, 4, 4], [4, 1, 2, 3], [4, 5, 6, 7]]);
+
+        let cleared = spread.clear_matches_at(0, 0, MatchRule::default());
+        assert_eq!(cleared.len(), 1);
+        assert_eq!(cleared[0].0, Line::Column(0));
+        assert_eq!(cleared[0].1.len(), 3);
+        assert_eq!(spread.remaining_cards().count(), 9);
+    }
+
+    #[test]
+    fn rows_can_be_cleared_too() {
+        let rule = MatchRule {
+            lines: MatchLines::ColumnsAndRows,
+            ..Default::default()
+        };
+        let mut spread = spread_of([[4, 4, 4, 4], [4, 1, 2, 3], [4, 5, 6, 7]]);
+
+        let cleared = spread.clear_matches_at(0, 0, rule);
+        let lines = cleared.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![Line::Column(0), Line::Row(0)]);
+        // The corner card is only reported once.
+        assert_eq!(cleared[0].1.len() + cleared[1].1.len(), 6);
+        assert_eq!(spread.remaining_cards().count(), 6);
+    }
+
+    #[test]
+    fn what_is_left_of_a_column_can_still_match() {
+        let rule = MatchRule {
+            lines: MatchLines::ColumnsAndRows,
+            ..Default::default()
+        };
+        let mut spread = spread_of([[1, 2, 3, 4], [8, 8, 8, 8], [9, 5, 6, 7]]);
+        spread.clear_matches_at(1, 0, rule);
+
+        spread.take_from(2, 0).unwrap();
+        let mut card = Card::new(1);
+        card.flip();
+        spread.place_at(card, 2, 0).unwrap();
+
+        let cleared = spread.clear_matches_at(2, 0, rule);
+        assert_eq!(cleared, vec![(Line::Column(0), vec![card, card])]);
+    }
+
+    #[test]
+    fn diagonals_can_be_cleared() {
+        let rule = MatchRule {
+            lines: MatchLines::ColumnsRowsAndDiagonals,
+            ..Default::default()
+        };
+        let mut spread = spread_of([[1, 2, 3, 6], [4, 5, 6, 7], [9, 6, 8, 2]]);
+
+        let cleared = spread.clear_matches_at(1, 2, rule);
+        assert_eq!(cleared.len(), 1);
+        assert_eq!(
+            cleared[0].0,
+            Line::Diagonal {
+                column: 3,
+                rightwards: false
+            }
+        );
+        assert!(spread.clear_matches_at(1, 1, rule).is_empty());
+    }
+
+    #[test]
+    fn negative_twos_can_be_kept() {
+        let rule = MatchRule {
+            keep_negative_twos: true,
+            ..Default::default()
+        };
+        let mut spread = spread_of([[-2, 1, 2, 3], [-2, 4, 5, 6], [-2, 7, 8, 9]]);
+
+        assert!(spread.clear_matches_at(0, 0, rule).is_empty());
+        assert_eq!(spread.score(), 39);
+    }
+
     #[test]
     fn a_player_spread_can_provide_counts() {
         let mut spread = init_player_spread();
@@ -564,6 +1147,37 @@ mod tests {
         assert_eq!(spread.flipped_cards(), 3);
     }
 
+    #[test]
+    fn a_matching_column_is_removed_and_returned() {
+        let mut spread = init_player_spread();
+        for row in 0..3 {
+            spread.take_from(row, 1).unwrap();
+            spread.place_at(Card::new(7), row, 1).unwrap();
+        }
+
+        spread.flip_at(0, 1).unwrap();
+        spread.flip_at(1, 1).unwrap();
+        assert!(spread.remove_column_if_matches(1).unwrap().is_empty());
+
+        assert_eq!(spread.flip_at(2, 1).unwrap(), CardValue::Seven);
+        let removed = spread.remove_column_if_matches(1).unwrap();
+        assert_eq!(removed.len(), 3);
+        assert!(removed
+            .iter()
+            .all(|c| c.get_value() == Some(CardValue::Seven)));
+        assert_eq!(spread.active_columns(), 3);
+    }
+
+    #[test]
+    fn cards_are_face_up_on_the_discard_pile() {
+        let mut discard_pile = DiscardPile::new();
+        discard_pile.put(Card::new(4));
+        assert_eq!(
+            discard_pile.top().unwrap().get_value(),
+            Some(CardValue::Four)
+        );
+    }
+
     #[test]
     fn a_filled_but_unflipped_player_spread_has_a_score_of_0() {
         let spread = init_player_spread();
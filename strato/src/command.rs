@@ -0,0 +1,127 @@
+use anyhow::Result;
+
+use crate::card::{PlayerSpread, Spot};
+use crate::game::{GameEvent, GameState, PlayerTurnError, StratoGame};
+use crate::player::{EndAction, StartAction};
+
+/// Something a player can do once the game has started.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerCommand {
+    /// Flip a card while deciding who goes first.
+    FlipToDetermineFirstPlayer {
+        row: usize,
+        column: usize,
+    },
+    StartTurn {
+        action: StartAction,
+    },
+    EndTurn {
+        action: EndAction,
+    },
+    Forfeit,
+}
+
+impl<'s> StratoGame<'s> {
+    /// Carry out a command for a player. Returns the events it caused, which subscribers
+    /// receive as well.
+    pub fn apply<S: Into<String> + Clone>(
+        &mut self,
+        player_id: S,
+        command: PlayerCommand,
+    ) -> Result<Vec<GameEvent>, PlayerTurnError> {
+        let (subscription_id, receiver) = self.subscribe_channel();
+
+        let result = match command {
+            PlayerCommand::FlipToDetermineFirstPlayer { row, column } => {
+                self.player_flip_to_determine_who_is_first(player_id, row, column)
+            }
+            PlayerCommand::StartTurn { action } => self.start_player_turn(player_id, action),
+            PlayerCommand::EndTurn { action } => self.end_player_turn(player_id, action),
+            PlayerCommand::Forfeit => self.forfeit(player_id),
+        };
+
+        self.unsubscribe(subscription_id);
+        result.map(|_| receiver.try_iter().collect())
+    }
+
+    /// Every command the player could successfully apply right now, so clients don't have to
+    /// find out by trial and error. Forfeiting is always possible during a game and is left out.
+    pub fn legal_actions<S: Into<String>>(&self, player_id: S) -> Vec<PlayerCommand> {
+        let Some(player_idx) = self.player_idx(player_id) else {
+            return vec![];
+        };
+        let player = &self.context.players[player_idx];
+
+        match self.state {
+            GameState::DetermineFirstPlayer => {
+                let Some(cards_allowed) = self.first_player_flips_allowed(player_idx) else {
+                    return vec![];
+                };
+                if player.spread.flipped_cards() >= cards_allowed {
+                    return vec![];
+                }
+
+                spots_where(&player.spread, |spot| spot == Spot::FaceDown)
+                    .map(|(row, column)| PlayerCommand::FlipToDetermineFirstPlayer { row, column })
+                    .collect()
+            }
+            GameState::Active | GameState::LastRound => {
+                if self.context.current_player_idx != Some(player_idx) {
+                    return vec![];
+                }
+
+                let Some(held_from) = player.held_from() else {
+                    let discard_pile_size = self.context.discard_pile.size();
+                    // An empty deck is refilled from everything but the top of the discard pile.
+                    let can_draw = self.context.deck.size() > 0 || discard_pile_size > 1;
+                    let can_take = discard_pile_size > 0;
+                    return [
+                        (can_draw, StartAction::DrawFromDeck),
+                        (can_take, StartAction::TakeFromDiscardPile),
+                    ]
+                    .into_iter()
+                    .filter(|(allowed, _)| *allowed)
+                    .map(|(_, action)| PlayerCommand::StartTurn { action })
+                    .collect();
+                };
+
+                let swaps =
+                    spots_where(&player.spread, |spot| spot != Spot::Empty).map(|(row, column)| {
+                        PlayerCommand::EndTurn {
+                            action: EndAction::Swap { row, column },
+                        }
+                    });
+                let can_flip =
+                    self.options.rules.discard_then_flip && held_from == StartAction::DrawFromDeck;
+                let flips = spots_where(&player.spread, |spot| spot == Spot::FaceDown)
+                    .filter(|_| can_flip)
+                    .map(|(row, column)| PlayerCommand::EndTurn {
+                        action: EndAction::Flip { row, column },
+                    });
+
+                swaps.chain(flips).collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// The row and column of every spot in the spread that satisfies `f`, row by row.
+fn spots_where(
+    spread: &PlayerSpread,
+    f: impl Fn(Spot) -> bool,
+) -> impl Iterator<Item = (usize, usize)> {
+    spread
+        .spots()
+        .into_iter()
+        .enumerate()
+        .flat_map(|(row, spots)| {
+            spots
+                .into_iter()
+                .enumerate()
+                .map(move |(column, spot)| (row, column, spot))
+        })
+        .filter(move |(_, _, spot)| f(*spot))
+        .map(|(row, column, _)| (row, column))
+}
@@ -1,12 +1,16 @@
-use std::rc::Rc;
+use std::sync::{mpsc, Arc};
 
 use anyhow::Result;
 use rand::distributions::Alphanumeric;
-use rand::Rng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use thiserror::Error;
 
-use crate::card::{Deck, DiscardPile};
+use crate::card::{Card, CardValue, Deck, DiscardPile, Line, PlayerSpread};
 use crate::player::{EndAction, Player, StartAction};
+use crate::replay::Action;
+use crate::rules::Rules;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum GameStartupError {
@@ -16,10 +20,33 @@ pub enum GameStartupError {
     PlayersListLocked,
     #[error("Not enough players to start the game.")]
     NotEnoughPlayers,
+    #[error(
+        "Player names must be 1 to {} characters long.",
+        Player::MAX_NAME_LENGTH
+    )]
+    InvalidPlayerName,
+    #[error("There is already a player called {0}.")]
+    DuplicatePlayerName(String),
+    #[error("Couldn't find a player with that ID.")]
+    PlayerDoesntExist,
     #[error(transparent)]
     PlayerSpreadError(#[from] crate::card::SpreadActionError),
     #[error("No more cards in the deck.")]
     DeckEmpty,
+    #[error("The game is full.")]
+    TooManyPlayers,
+    #[error("There aren't enough cards in the deck to deal to every player.")]
+    DeckTooSmall,
+    #[error("A spread needs at least two rows and one column.")]
+    InvalidSpreadSize,
+    #[error("The seating order must list every player exactly once.")]
+    InvalidSeating,
+    #[error("There is no player in the seat chosen to go first.")]
+    FirstPlayerDoesntExist,
+    #[error("The youngest-to-oldest order names a seat with no player in it.")]
+    InvalidTieBreakOrder,
+    #[error("Players can't be picked by seat when the seats are drawn at random.")]
+    SeatsNotKnownYet,
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -30,6 +57,8 @@ pub enum PlayerTurnError {
     NotDeterminingFirstPlayer,
     #[error("You have already flipped your cards to determine who goes first.")]
     TooManyCardsFlipped,
+    #[error("Only the players tied for going first can flip right now.")]
+    NotInTieBreak,
     #[error("The game has not started yet.")]
     GameNotStarted,
     #[error("You can't start your turn when it is already started.")]
@@ -38,6 +67,10 @@ pub enum PlayerTurnError {
     TurnNotStarted,
     #[error("It is not your turn.")]
     NotYourTurn,
+    #[error("A card taken from the discard pile must be swapped into your spread.")]
+    CantFlipWithCardFromDiscardPile,
+    #[error("These rules don't allow discarding the drawn card to flip one instead.")]
+    DiscardThenFlipNotAllowed,
     #[error(transparent)]
     PlayerActionError(#[from] crate::player::PlayerActionError),
     #[error(transparent)]
@@ -48,51 +81,168 @@ pub enum PlayerTurnError {
     DiscardPileEmpty,
 }
 
+#[derive(Error, Debug, PartialEq)]
+pub enum UndoError {
+    #[error("Undo is not allowed in this game.")]
+    NotAllowed,
+    #[error("There is nothing to undo. Only the current player's last turn can be taken back.")]
+    NothingToUndo,
+    #[error("The game is over.")]
+    GameOver,
+}
+
 #[derive(Debug, Clone)]
 pub struct StratoGame<'s> {
     pub state: GameState,
     pub context: GameContext,
-    subscriber: Option<Rc<Subscriber<'s>>>,
+    pub(crate) options: GameOptions,
+    /// The seed the game was created with.
+    pub(crate) seed: u64,
+    /// Drives player IDs, shuffling and random tie-breaks, so a seed reproduces the whole game.
+    pub(crate) rng: ChaCha8Rng,
+    /// Every command the game has accepted, in order.
+    pub(crate) actions: Vec<Action>,
+    /// How many players may join.
+    pub(crate) max_players: usize,
+    /// The game as it was before each of the current player's turn actions, newest last.
+    undo_points: Vec<UndoPoint>,
+    subscribers: Vec<(SubscriptionId, Arc<Subscriber<'s>>)>,
+    next_subscription_id: usize,
 }
 
 impl<'s> StratoGame<'s> {
+    pub const DEFAULT_MAX_PLAYERS: usize = 8;
+
     pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// Create a game whose player IDs, shuffles and random choices are all determined by the seed.
+    pub fn with_seed(seed: u64) -> Self {
         Self {
             state: GameState::default(),
             context: GameContext::default(),
-            subscriber: None,
+            options: GameOptions::default(),
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            actions: vec![],
+            max_players: Self::DEFAULT_MAX_PLAYERS,
+            undo_points: vec![],
+            subscribers: vec![],
+            next_subscription_id: 0,
         }
     }
 
+    /// The seed the game was created with. Together with `actions`, this reproduces the game.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
     fn update_state(&mut self, state: GameState) {
-        self.state = state;
-        self.notify(GameEvent::StateChange(&self.state));
+        self.state = state.clone();
+        self.notify(GameEvent::StateChange(state));
+    }
+
+    /// Call `f` with every event from now on. Keep the returned ID to unsubscribe later.
+    pub fn subscribe(&mut self, f: impl Fn(GameEvent) + Send + Sync + 's) -> SubscriptionId {
+        let id = SubscriptionId(self.next_subscription_id);
+        self.next_subscription_id += 1;
+        self.subscribers.push((id, Arc::new(Subscriber::new(f))));
+        id
+    }
+
+    /// Receive every event from now on through a channel, e.g. to forward them from another
+    /// thread. Events stop being sent once the receiver is dropped.
+    pub fn subscribe_channel(&mut self) -> (SubscriptionId, mpsc::Receiver<GameEvent>) {
+        let (sender, receiver) = mpsc::channel();
+        let id = self.subscribe(move |e| {
+            let _ = sender.send(e);
+        });
+        (id, receiver)
     }
 
-    pub fn subscribe(&mut self, f: impl Fn(GameEvent) + 's) {
-        self.subscriber = Some(Rc::new(Subscriber::new(f)));
+    /// Stop sending events to a subscriber. Returns `false` if it was already unsubscribed.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let count = self.subscribers.len();
+        self.subscribers.retain(|(sub_id, _)| *sub_id != id);
+        self.subscribers.len() != count
     }
 
-    pub fn unsubscribe(&mut self) {
-        self.subscriber = None;
+    pub fn unsubscribe_all(&mut self) {
+        self.subscribers.clear();
     }
 
     fn notify(&self, event: GameEvent) {
-        if let Some(subscriber) = &self.subscriber {
-            (subscriber.0)(event);
+        for (_, subscriber) in self.subscribers.iter() {
+            (subscriber.0)(event.clone());
+        }
+    }
+
+    /// The house rules the game is played by.
+    pub fn rules(&self) -> &Rules {
+        &self.options.rules
+    }
+
+    pub fn max_players(&self) -> usize {
+        self.max_players
+    }
+
+    /// Change how many players may join, before the game starts.
+    pub fn set_max_players(&mut self, max_players: usize) -> Result<(), GameStartupError> {
+        if self.state != GameState::WaitingForPlayers {
+            return Err(GameStartupError::PlayersListLocked);
+        } else if max_players < 2 {
+            return Err(GameStartupError::NotEnoughPlayers);
+        } else if max_players < self.context.players.len() {
+            return Err(GameStartupError::TooManyPlayers);
         }
+
+        self.max_players = max_players;
+        self.actions.push(Action::SetMaxPlayers { max_players });
+
+        Ok(())
     }
 
-    pub fn add_player(&mut self, player_name: &'static str) -> Result<String, GameStartupError> {
+    /// Add a player to the lobby. Their name is trimmed, and must be unique within the game
+    /// regardless of case.
+    pub fn add_player<S: Into<String>>(
+        &mut self,
+        player_name: S,
+    ) -> Result<String, GameStartupError> {
         if self.state == GameState::WaitingForPlayers {
-            let player_id = rand::thread_rng()
+            if self.context.players.len() >= self.max_players {
+                return Err(GameStartupError::TooManyPlayers);
+            }
+
+            let player_name = player_name.into().trim().to_string();
+            let name_length = player_name.chars().count();
+            if name_length == 0 || name_length > Player::MAX_NAME_LENGTH {
+                return Err(GameStartupError::InvalidPlayerName);
+            }
+            if self
+                .context
+                .players
+                .iter()
+                .any(|p| p.name().to_lowercase() == player_name.to_lowercase())
+            {
+                return Err(GameStartupError::DuplicatePlayerName(player_name));
+            }
+
+            let player_id = (&mut self.rng)
                 .sample_iter(&Alphanumeric)
                 .take(30)
                 .map(char::from)
                 .collect::<String>();
 
-            let player = Player::new(player_id.clone(), player_name);
+            let player = Player::new(player_id.clone(), player_name.clone());
             self.context.players.push(player);
+            self.actions.push(Action::AddPlayer {
+                name: player_name.clone(),
+            });
+            self.notify(GameEvent::PlayerJoined {
+                player_id: player_id.clone(),
+                name: player_name,
+            });
 
             Ok(player_id)
         } else {
@@ -100,10 +250,187 @@ impl<'s> StratoGame<'s> {
         }
     }
 
+    /// Seat players who already have IDs, such as those carried over from a previous hand.
+    pub(crate) fn seat_returning_players(
+        &mut self,
+        players: Vec<(String, String)>,
+    ) -> Result<(), GameStartupError> {
+        if self.state != GameState::WaitingForPlayers {
+            return Err(GameStartupError::PlayersListLocked);
+        } else if self.context.players.len() + players.len() > self.max_players {
+            return Err(GameStartupError::TooManyPlayers);
+        }
+        for (player_id, name) in &players {
+            if self.player_idx(player_id).is_some()
+                || self
+                    .context
+                    .players
+                    .iter()
+                    .any(|p| p.name().to_lowercase() == name.to_lowercase())
+            {
+                return Err(GameStartupError::DuplicatePlayerName(name.clone()));
+            }
+            self.context
+                .players
+                .push(Player::new(player_id.clone(), name.clone()));
+        }
+
+        self.actions.push(Action::SeatPlayers { players });
+
+        Ok(())
+    }
+
     pub fn list_players(&self) -> Vec<Player> {
         self.context.players.clone()
     }
 
+    /// Take a player back out of the lobby, before the game starts.
+    pub fn remove_player<S: Into<String> + Clone>(
+        &mut self,
+        player_id: S,
+    ) -> Result<(), GameStartupError> {
+        if self.state != GameState::WaitingForPlayers {
+            return Err(GameStartupError::PlayersListLocked);
+        }
+
+        let player_idx = self
+            .player_idx(player_id)
+            .ok_or(GameStartupError::PlayerDoesntExist)?;
+
+        let player = self.context.players.remove(player_idx);
+        self.actions.push(Action::RemovePlayer {
+            player_id: player.id(),
+        });
+        self.notify(GameEvent::PlayerLeft {
+            player_id: player.id(),
+        });
+
+        Ok(())
+    }
+
+    /// A player leaves a game in progress. Their spread leaves with them, and a card they were
+    /// holding goes back on the discard pile. The game ends if only one player is left.
+    pub fn forfeit<S: Into<String> + Clone>(
+        &mut self,
+        player_id: S,
+    ) -> Result<(), PlayerTurnError> {
+        if !matches!(
+            self.state,
+            GameState::DetermineFirstPlayer | GameState::Active | GameState::LastRound
+        ) {
+            return Err(PlayerTurnError::GameNotStarted);
+        }
+
+        let player_idx = self
+            .player_idx(player_id)
+            .ok_or(PlayerTurnError::PlayerDoesntExist)?;
+
+        let mut player = self.context.players.remove(player_idx);
+        if let Some(card) = player.release() {
+            self.context.discard_pile.put(card);
+        }
+        // Undo points refer to players by index, which just changed.
+        self.undo_points.clear();
+        self.actions.push(Action::Forfeit {
+            player_id: player.id(),
+        });
+        self.notify(GameEvent::PlayerLeft {
+            player_id: player.id(),
+        });
+
+        let players_count = self.context.players.len();
+        let was_their_turn = self.context.current_player_idx == Some(player_idx);
+        let was_last = self.context.last_player_idx == Some(player_idx);
+        let was_starting = self.context.starting_player_idx == Some(player_idx);
+
+        // Everyone seated after the player moves up one seat.
+        let shift = |idx: usize| if idx > player_idx { idx - 1 } else { idx };
+        self.context.tied_for_first = self
+            .context
+            .tied_for_first
+            .iter()
+            .filter(|idx| **idx != player_idx)
+            .map(|idx| shift(*idx))
+            .collect();
+        // A tie-break needs two players. With one left, they go first; with none, it's called off.
+        let won_tie_break = if self.context.tied_for_first.len() < 2 {
+            self.context.tie_break_flips = 0;
+            self.context.tied_for_first.pop()
+        } else {
+            None
+        };
+        if let FirstPlayerTieBreak::Youngest(youngest_to_oldest) =
+            &mut self.options.first_player_tie_break
+        {
+            *youngest_to_oldest = youngest_to_oldest
+                .iter()
+                .filter(|idx| **idx != player_idx)
+                .map(|idx| shift(*idx))
+                .collect();
+        }
+        self.context.finisher_idx = self
+            .context
+            .finisher_idx
+            .filter(|idx| *idx != player_idx)
+            .map(shift);
+        // If the last player leaves, the player before them takes the last turn instead.
+        self.context.last_player_idx = self.context.last_player_idx.map(|idx| {
+            if idx == player_idx {
+                (idx + players_count - 1) % players_count
+            } else {
+                shift(idx)
+            }
+        });
+
+        // If the player who starts each round leaves, the player after them starts them instead.
+        self.context.starting_player_idx = self.context.starting_player_idx.map(|idx| {
+            if idx == player_idx {
+                idx % players_count
+            } else {
+                shift(idx)
+            }
+        });
+
+        if players_count < 2 || (was_their_turn && was_last) {
+            self.update_state(GameState::Ended);
+            self.handle_end();
+            return Ok(());
+        }
+
+        if let Some(current_player_idx) = self.context.current_player_idx {
+            if was_their_turn && was_starting {
+                // The round had only just begun, so it carries on with the next player.
+                self.context.turn += 1;
+                self.set_current_player(current_player_idx % players_count);
+            } else if was_their_turn {
+                // The next player has moved into the seat that just emptied.
+                self.pass_turn_to(current_player_idx % players_count);
+            } else {
+                self.context.current_player_idx = Some(shift(current_player_idx));
+            }
+        }
+
+        if self.state == GameState::DetermineFirstPlayer {
+            let first_player_idx =
+                won_tie_break.or_else(|| self.check_if_first_player_determined());
+            if let Some(first_player_idx) = first_player_idx {
+                self.context.tied_for_first.clear();
+                self.update_state(GameState::Active);
+                self.start_first_turn(first_player_idx);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn player_idx<S: Into<String>>(&self, player_id: S) -> Option<usize> {
+        let player_id = player_id.into();
+        self.context
+            .players
+            .iter()
+            .position(|p| p.id() == player_id)
+    }
+
     pub fn get_player<S: Into<String> + Clone>(&self, player_id: S) -> Option<&Player> {
         self.context
             .players
@@ -111,6 +438,62 @@ impl<'s> StratoGame<'s> {
             .find(|p| p.id() == player_id.clone().into())
     }
 
+    /// The round being played, counting from 1. A round ends when every player has had a turn.
+    /// Zero until the first turn begins.
+    pub fn round(&self) -> usize {
+        self.context.round
+    }
+
+    /// The turn being played, counting from 1 across the whole game. Zero until the first
+    /// turn begins.
+    pub fn turn(&self) -> usize {
+        self.context.turn
+    }
+
+    /// Every turn that has been completed, oldest first.
+    pub fn turns(&self) -> &[TurnRecord] {
+        &self.context.turns
+    }
+
+    /// Index of the player who flipped their whole spread first, if anyone has yet.
+    pub fn finisher_idx(&self) -> Option<usize> {
+        self.context.finisher_idx
+    }
+
+    /// Everyone's score breakdown for the hand, once the game has ended.
+    pub fn scores(&self) -> Option<&[PlayerScore]> {
+        if self.state == GameState::Ended {
+            Some(&self.context.scores)
+        } else {
+            None
+        }
+    }
+
+    /// Final standings, once the game has ended.
+    pub fn result(&self) -> Option<&GameResult> {
+        self.context.result.as_ref()
+    }
+
+    /// Set up a fresh game with the same players (and subscribers), ready to be started
+    /// for the next hand of a match. Its seed comes from this game's randomness, and the
+    /// players are seated through its action log, so the hand can be replayed on its own.
+    pub(crate) fn next_hand(&self) -> Result<Self, GameStartupError> {
+        let mut game = Self::with_seed(self.rng.clone().gen());
+        if self.max_players != game.max_players {
+            game.set_max_players(self.max_players)?;
+        }
+        game.seat_returning_players(
+            self.context
+                .players
+                .iter()
+                .map(|p| (p.id(), p.name()))
+                .collect(),
+        )?;
+        game.subscribers = self.subscribers.clone();
+        game.next_subscription_id = self.next_subscription_id;
+        Ok(game)
+    }
+
     pub fn start(&mut self) -> Result<(), GameStartupError> {
         self.handle_start(GameOptions::default())
     }
@@ -125,17 +508,85 @@ impl<'s> StratoGame<'s> {
         } else if self.context.players.len() < 2 {
             return Err(GameStartupError::NotEnoughPlayers);
         } else if self.state == GameState::WaitingForPlayers {
-            self.update_state(GameState::Startup);
+            let rules = &options.rules;
+            let spread_size = rules.spread_size;
+            if spread_size.rows < 2 || spread_size.columns < 1 {
+                return Err(GameStartupError::InvalidSpreadSize);
+            }
+            // Everyone's spread, plus the first card of the discard pile.
+            let cards_needed = self.context.players.len() * spread_size.cards() + 1;
+            let deck_size = rules.deck_composition.size();
+            let deck_sets = options.deck_sets.unwrap_or_else(|| {
+                deck_sets_for(self.context.players.len(), cards_needed, deck_size)
+            });
+            if cards_needed > deck_size * deck_sets {
+                return Err(GameStartupError::DeckTooSmall);
+            }
+            if let Seating::Explicit(player_ids) = &options.seating {
+                let mut seated = player_ids.clone();
+                seated.sort();
+                seated.dedup();
+                if seated.len() != player_ids.len()
+                    || player_ids.len() != self.context.players.len()
+                    || player_ids.iter().any(|id| self.player_idx(id).is_none())
+                {
+                    return Err(GameStartupError::InvalidSeating);
+                }
+            }
+            let picks_by_seat = options.first_player_idx.is_some()
+                || matches!(
+                    options.first_player_tie_break,
+                    FirstPlayerTieBreak::Youngest(_)
+                );
+            if options.seating == Seating::Random && picks_by_seat {
+                return Err(GameStartupError::SeatsNotKnownYet);
+            }
+            let players_count = self.context.players.len();
+            if options
+                .first_player_idx
+                .is_some_and(|idx| idx >= players_count)
+            {
+                return Err(GameStartupError::FirstPlayerDoesntExist);
+            }
+            if let FirstPlayerTieBreak::Youngest(youngest_to_oldest) =
+                &options.first_player_tie_break
+            {
+                if youngest_to_oldest.iter().any(|idx| *idx >= players_count) {
+                    return Err(GameStartupError::InvalidTieBreakOrder);
+                }
+            }
 
-            self.context.deck.shuffle();
-            let top_card = self.context.deck.draw().unwrap();
+            self.update_state(GameState::Startup);
+            if let Some(seed) = options.seed {
+                self.rng = ChaCha8Rng::seed_from_u64(seed);
+            }
+            self.actions.push(Action::Start {
+                options: options.clone(),
+            });
+            self.options = GameOptions {
+                deck_sets: Some(deck_sets),
+                ..options.clone()
+            };
+
+            self.context.deck = Deck::with_composition(&options.rules.deck_composition, deck_sets);
+            self.context.deck.shuffle_with(&mut self.rng);
+            let mut top_card = self.context.deck.draw().unwrap();
+            top_card.flip();
             self.context.discard_pile.put(top_card);
-            // TODO: shuffle player order?
+            self.seat_players(&options.seating);
             self.deal_cards_to_players()?;
+            self.notify(GameEvent::CardsDealt {
+                cards_per_player: spread_size.cards(),
+                discard_pile_top: top_card.get_value().unwrap(),
+            });
 
             if let Some(first_player_idx) = options.first_player_idx {
-                self.context.current_player_idx = Some(first_player_idx);
                 self.update_state(GameState::Active);
+                self.start_first_turn(first_player_idx);
+            } else if !options.rules.initial_flip {
+                let first_player_idx = self.rng.gen_range(0..self.context.players.len());
+                self.update_state(GameState::Active);
+                self.start_first_turn(first_player_idx);
             } else {
                 self.update_state(GameState::DetermineFirstPlayer);
             }
@@ -144,6 +595,20 @@ impl<'s> StratoGame<'s> {
         Ok(())
     }
 
+    fn seat_players(&mut self, seating: &Seating) {
+        match seating {
+            Seating::JoinOrder => {}
+            Seating::Random => self.context.players.shuffle(&mut self.rng),
+            Seating::Explicit(player_ids) => self
+                .context
+                .players
+                .sort_by_key(|p| player_ids.iter().position(|id| *id == p.id())),
+        }
+        self.notify(GameEvent::PlayersSeated {
+            player_ids: self.context.players.iter().map(Player::id).collect(),
+        });
+    }
+
     fn handle_end(&mut self) {
         if self.state != GameState::Ended {
             return;
@@ -153,25 +618,50 @@ impl<'s> StratoGame<'s> {
             player.spread.flip_all();
         }
 
-        let winner_idx = self
+        let spread_scores = self
             .context
             .players
             .iter()
-            .enumerate()
-            .max_by_key(|(_, p)| p.spread.score())
-            .map(|(idx, _)| idx)
-            .unwrap();
+            .map(|p| p.spread.score())
+            .collect::<Vec<_>>();
 
-        // TODO: handle case where there is a tie
+        self.context.scores = self
+            .context
+            .players
+            .iter()
+            .enumerate()
+            .map(|(idx, player)| {
+                let spread_score = spread_scores[idx];
+                let penalty = match self.context.finisher_idx {
+                    Some(finisher_idx)
+                        if finisher_idx == idx && self.options.rules.finisher_penalty =>
+                    {
+                        finisher_penalty(&spread_scores, finisher_idx)
+                    }
+                    _ => 0,
+                };
+
+                PlayerScore {
+                    player_id: player.id(),
+                    spread_score,
+                    penalty,
+                    final_score: spread_score + penalty,
+                }
+            })
+            .collect();
 
-        self.context.winner_idx = Some(winner_idx);
+        let result = GameResult::from_scores(&self.context.scores);
+        self.context.result = Some(result.clone());
+        self.notify(GameEvent::GameEnded { result });
     }
 
     fn deal_cards_to_players(&mut self) -> Result<(), GameStartupError> {
         if self.state == GameState::Startup {
+            let size = self.options.rules.spread_size;
             for player in self.context.players.iter_mut() {
-                for row in 0..3 {
-                    for column in 0..4 {
+                player.spread = PlayerSpread::with_size(size);
+                for row in 0..size.rows {
+                    for column in 0..size.columns {
                         let card = self
                             .context
                             .deck
@@ -186,7 +676,7 @@ impl<'s> StratoGame<'s> {
         Ok(())
     }
 
-    pub fn player_flip_to_determine_who_is_first<'a, S: Into<String> + Clone>(
+    pub fn player_flip_to_determine_who_is_first<S: Into<String> + Clone>(
         &mut self,
         player_id: S,
         row: usize,
@@ -196,77 +686,154 @@ impl<'s> StratoGame<'s> {
             return Err(PlayerTurnError::NotDeterminingFirstPlayer);
         }
 
-        let player = self
-            .context
-            .players
-            .iter_mut()
-            .find(|p| p.id() == player_id.clone().into())
+        let player_idx = self
+            .player_idx(player_id)
             .ok_or(PlayerTurnError::PlayerDoesntExist)?;
 
-        if player.spread.flipped_cards() >= 2 {
+        let cards_allowed = self
+            .first_player_flips_allowed(player_idx)
+            .ok_or(PlayerTurnError::NotInTieBreak)?;
+
+        let player = &mut self.context.players[player_idx];
+
+        if player.spread.flipped_cards() >= cards_allowed {
             return Err(PlayerTurnError::TooManyCardsFlipped);
         }
 
-        player.spread.flip_at(row, column)?;
+        let value = player.spread.flip_at(row, column)?;
+        let player_id = player.id();
+        self.actions.push(Action::FlipToDetermineFirstPlayer {
+            player_id: player_id.clone(),
+            row,
+            column,
+        });
+        self.notify(GameEvent::CardFlipped {
+            player_id,
+            row,
+            column,
+            value,
+        });
 
         if let Some(first_player_idx) = self.check_if_first_player_determined() {
-            self.context.current_player_idx = Some(first_player_idx);
+            self.context.tied_for_first.clear();
             self.update_state(GameState::Active);
+            self.start_first_turn(first_player_idx);
         }
 
         Ok(())
     }
 
-    fn check_if_first_player_determined(&self) -> Option<usize> {
-        let all_players_have_two_cards_flipped = self
-            .context
-            .players
+    /// How many cards the player may have flipped while deciding who goes first, or `None` if
+    /// they are out of a tie-break.
+    pub(crate) fn first_player_flips_allowed(&self, player_idx: usize) -> Option<usize> {
+        if self.context.tied_for_first.is_empty() {
+            Some(2)
+        } else if self.context.tied_for_first.contains(&player_idx) {
+            Some(2 + self.context.tie_break_flips)
+        } else {
+            None
+        }
+    }
+
+    /// Once everyone still in contention has flipped their cards, the highest score goes first.
+    /// A tie is settled according to the `FirstPlayerTieBreak` option.
+    fn check_if_first_player_determined(&mut self) -> Option<usize> {
+        let in_contention = if self.context.tied_for_first.is_empty() {
+            (0..self.context.players.len()).collect::<Vec<_>>()
+        } else {
+            self.context.tied_for_first.clone()
+        };
+        let cards_needed = 2 + self.context.tie_break_flips;
+
+        let all_contenders_have_flipped = in_contention
             .iter()
-            .all(|p| p.spread.flipped_cards() == 2);
+            .all(|idx| self.context.players[*idx].spread.flipped_cards() == cards_needed);
 
-        if all_players_have_two_cards_flipped {
-            let highest_score_idx = self
-                .context
-                .players
+        if !all_contenders_have_flipped {
+            return None;
+        }
+
+        let highest_score = in_contention
+            .iter()
+            .map(|idx| self.context.players[*idx].spread.score())
+            .max()
+            .unwrap();
+        let tied = in_contention
+            .into_iter()
+            .filter(|idx| self.context.players[*idx].spread.score() == highest_score)
+            .collect::<Vec<_>>();
+
+        if tied.len() == 1 {
+            return Some(tied[0]);
+        }
+
+        match &self.options.first_player_tie_break {
+            FirstPlayerTieBreak::FlipAnotherCard => {
+                let all_have_cards_left = tied
+                    .iter()
+                    .all(|idx| !self.context.players[*idx].spread.is_all_flipped());
+
+                if all_have_cards_left {
+                    self.context.tied_for_first = tied;
+                    self.context.tie_break_flips += 1;
+                    None
+                } else {
+                    // Nothing left to flip, so fall back to seat order.
+                    Some(tied[0])
+                }
+            }
+            FirstPlayerTieBreak::Random => {
+                let pick = self.rng.gen_range(0..tied.len());
+                Some(tied[pick])
+            }
+            FirstPlayerTieBreak::Youngest(youngest_to_oldest) => youngest_to_oldest
                 .iter()
-                .enumerate()
-                .max_by_key(|(_, p)| p.spread.score())
-                .map(|(idx, _)| idx)
-                .unwrap();
-            return Some(highest_score_idx);
+                .find(|idx| tied.contains(idx))
+                .copied()
+                .or(Some(tied[0])),
         }
+    }
 
-        None
+    /// Indexes of the players who are tied for going first and must flip another card.
+    /// Empty unless a tie-break is underway.
+    pub fn tied_for_first(&self) -> &[usize] {
+        &self.context.tied_for_first
     }
 
-    pub fn start_player_turn<'a, S: Into<String> + Clone>(
+    pub fn start_player_turn<S: Into<String> + Clone>(
         &mut self,
         player_id: S,
         action: StartAction,
     ) -> Result<(), PlayerTurnError> {
-        if self.state != GameState::Active {
+        if !matches!(self.state, GameState::Active | GameState::LastRound) {
             return Err(PlayerTurnError::GameNotStarted);
         }
 
         let player_idx = self
-            .context
-            .players
-            .iter()
-            .position(|p| p.id() == player_id.clone().into())
+            .player_idx(player_id)
             .ok_or(PlayerTurnError::PlayerDoesntExist)?;
 
         self.check_if_player_turn(player_idx)?;
 
-        let player = &mut self.context.players[player_idx];
-
-        if player.holding().is_some() {
+        if self.context.players[player_idx].holding().is_some() {
             return Err(PlayerTurnError::TurnAlreadyStarted);
         }
 
-        match action {
+        let undo_point = self.undo_point(player_idx);
+
+        if action == StartAction::DrawFromDeck && self.context.deck.size() == Deck::EMPTY_SIZE {
+            self.reshuffle_discard_pile_into_deck();
+        }
+
+        let player = &mut self.context.players[player_idx];
+
+        let event = match action {
             StartAction::DrawFromDeck => {
                 let card = self.context.deck.draw().ok_or(PlayerTurnError::DeckEmpty)?;
-                player.hold(card)?;
+                player.hold(card, action)?;
+                GameEvent::CardDrawn {
+                    player_id: player.id(),
+                }
             }
             StartAction::TakeFromDiscardPile => {
                 let card = self
@@ -274,86 +841,232 @@ impl<'s> StratoGame<'s> {
                     .discard_pile
                     .take()
                     .ok_or(PlayerTurnError::DiscardPileEmpty)?;
-                player.hold(card)?;
+                player.hold(card, action)?;
+                GameEvent::CardTakenFromDiscard {
+                    player_id: player.id(),
+                    value: card.value(),
+                }
             }
-        }
+        };
+
+        self.actions.push(Action::StartTurn {
+            player_id: player.id(),
+            action,
+        });
+        self.push_undo_point(undo_point);
+        self.notify(event);
 
         Ok(())
     }
 
-    pub fn end_player_turn<'a, S: Into<String> + Clone>(
+    /// When the deck runs out, everything but the top of the discard pile is turned face-down
+    /// and shuffled to form a new deck.
+    fn reshuffle_discard_pile_into_deck(&mut self) {
+        let cards = self.context.discard_pile.take_all_but_top();
+        if cards.is_empty() {
+            return;
+        }
+
+        let cards_count = cards.len();
+        self.context.deck.refill(cards);
+        self.context.deck.shuffle_with(&mut self.rng);
+        self.notify(GameEvent::DeckReshuffled { cards: cards_count });
+    }
+
+    pub fn end_player_turn<S: Into<String> + Clone>(
         &mut self,
         player_id: S,
         action: EndAction,
     ) -> Result<(), PlayerTurnError> {
-        if self.state != GameState::Active {
+        if !matches!(self.state, GameState::Active | GameState::LastRound) {
             return Err(PlayerTurnError::GameNotStarted);
         }
 
         let player_idx = self
-            .context
-            .players
-            .iter()
-            .position(|p| p.id() == player_id.clone().into())
+            .player_idx(player_id)
             .ok_or(PlayerTurnError::PlayerDoesntExist)?;
 
         self.check_if_player_turn(player_idx)?;
 
+        let undo_point = self.undo_point(player_idx);
         let players = &mut self.context.players;
         let players_count = players.len();
         let player = players.get_mut(player_idx).unwrap();
 
-        let card_from_hand = player.release().ok_or(PlayerTurnError::TurnNotStarted)?;
+        let held_from = player.held_from().ok_or(PlayerTurnError::TurnNotStarted)?;
+        if !self.options.rules.discard_then_flip && matches!(action, EndAction::Flip { .. }) {
+            return Err(PlayerTurnError::DiscardThenFlipNotAllowed);
+        }
+        if held_from == StartAction::TakeFromDiscardPile && matches!(action, EndAction::Flip { .. })
+        {
+            return Err(PlayerTurnError::CantFlipWithCardFromDiscardPile);
+        }
 
-        match action {
+        let player_id = player.id();
+        let mut events = vec![];
+
+        // The spread is checked before letting go of the card, so a bad spot doesn't lose it.
+        let card = match action {
             EndAction::Swap { row, column } => {
                 let selected_card = player.spread.take_from(row, column)?;
+                let card_from_hand = player.release().ok_or(PlayerTurnError::TurnNotStarted)?;
                 player.spread.place_at(card_from_hand, row, column)?;
                 self.context.discard_pile.put(selected_card);
+                events.push(GameEvent::CardSwapped {
+                    player_id: player_id.clone(),
+                    row,
+                    column,
+                    placed: card_from_hand.value(),
+                    discarded: selected_card.value(),
+                });
+                card_from_hand.value()
             }
             EndAction::Flip { row, column } => {
-                player.spread.flip_at(row, column)?;
+                let value = player.spread.flip_at(row, column)?;
+                let card_from_hand = player.release().ok_or(PlayerTurnError::TurnNotStarted)?;
                 self.context.discard_pile.put(card_from_hand);
+                events.push(GameEvent::CardFlipped {
+                    player_id: player_id.clone(),
+                    row,
+                    column,
+                    value,
+                });
+                events.push(GameEvent::CardDiscarded {
+                    player_id: player_id.clone(),
+                    value: card_from_hand.value(),
+                });
+                card_from_hand.value()
             }
-        }
+        };
+
+        self.actions.push(Action::EndTurn {
+            player_id: player_id.clone(),
+            action,
+        });
+        self.context.turns.push(TurnRecord {
+            turn: self.context.turn,
+            round: self.context.round,
+            player_id: player_id.clone(),
+            start: held_from,
+            end: action,
+            card,
+        });
 
         match action {
-            EndAction::Swap { column, .. } | EndAction::Flip { column, .. } => {
-                player.spread.remove_column_if_matches(column)?;
+            EndAction::Swap { row, column } | EndAction::Flip { row, column } => {
+                let cleared =
+                    player
+                        .spread
+                        .clear_matches_at(row, column, self.options.rules.match_rule);
+                for (line, cards) in cleared {
+                    events.push(GameEvent::LineCleared {
+                        player_id: player_id.clone(),
+                        line,
+                        cards: cards.iter().map(Card::value).collect(),
+                    });
+                }
             }
         }
 
-        if self.state == GameState::LastRound {
-            // TODO: make this cleaner
-            if player_idx == last_player_idx(players_count, self.context.finisher_idx.unwrap()) {
-                self.update_state(GameState::Ended);
-                self.handle_end();
-                return Ok(());
-            }
+        let is_all_flipped = player.spread.is_all_flipped();
+        for event in events {
+            self.notify(event);
         }
 
-        if self.state == GameState::Active && player.spread.is_all_flipped() {
-            self.context.finisher_idx = Some(player_idx);
-            self.update_state(GameState::LastRound);
+        if self.state == GameState::LastRound && self.context.last_player_idx == Some(player_idx) {
+            self.update_state(GameState::Ended);
+            self.handle_end();
+            return Ok(());
         }
 
-        if player_idx == self.context.players.len() {
-            self.advance_round();
+        if self.state == GameState::Active && is_all_flipped {
+            self.context.finisher_idx = Some(player_idx);
+            // Everyone else gets one more turn, ending with the player before the finisher.
+            self.context.last_player_idx = Some((player_idx + players_count - 1) % players_count);
+            self.notify(GameEvent::LastRoundTriggered { player_id });
+            self.update_state(GameState::LastRound);
         }
 
+        self.push_undo_point(undo_point);
         self.advance_player_turn();
 
         Ok(())
     }
 
-    fn advance_round(&mut self) {
-        self.context.round += 1;
+    fn start_first_turn(&mut self, player_idx: usize) {
+        self.context.starting_player_idx = Some(player_idx);
+        self.context.round = 1;
+        self.context.turn = 1;
+        self.set_current_player(player_idx);
     }
 
     fn advance_player_turn(&mut self) {
         if let Some(current_player_idx) = self.context.current_player_idx {
-            self.context.current_player_idx =
-                Some((current_player_idx + 1) % self.context.players.len());
+            self.pass_turn_to((current_player_idx + 1) % self.context.players.len());
+        }
+    }
+
+    /// Start the next turn, and the next round once play is back around to the starting player.
+    fn pass_turn_to(&mut self, player_idx: usize) {
+        self.context.turn += 1;
+        if self.context.starting_player_idx == Some(player_idx) {
+            self.context.round += 1;
+        }
+        self.set_current_player(player_idx);
+    }
+
+    fn set_current_player(&mut self, player_idx: usize) {
+        self.context.current_player_idx = Some(player_idx);
+        self.notify(GameEvent::TurnAdvanced {
+            player_id: self.context.players[player_idx].id(),
+        });
+    }
+
+    /// Take back the most recent start or end of a turn, if undo is allowed in this game.
+    /// Only the current player's actions can be undone; once the next player acts, they are final.
+    pub fn undo(&mut self) -> Result<(), UndoError> {
+        if !self.options.allow_undo {
+            return Err(UndoError::NotAllowed);
+        }
+        if self.state == GameState::Ended {
+            return Err(UndoError::GameOver);
+        }
+
+        let undo_point = self.undo_points.pop().ok_or(UndoError::NothingToUndo)?;
+        self.context = undo_point.context;
+        self.rng = undo_point.rng;
+        self.actions.truncate(undo_point.actions_len);
+        self.notify(GameEvent::TurnUndone {
+            player_id: self.context.players[undo_point.player_idx].id(),
+        });
+        if self.state != undo_point.state {
+            self.update_state(undo_point.state);
+        }
+
+        Ok(())
+    }
+
+    fn undo_point(&self, player_idx: usize) -> Option<UndoPoint> {
+        self.options.allow_undo.then(|| UndoPoint {
+            player_idx,
+            state: self.state.clone(),
+            context: self.context.clone(),
+            rng: self.rng.clone(),
+            actions_len: self.actions.len(),
+        })
+    }
+
+    fn push_undo_point(&mut self, undo_point: Option<UndoPoint>) {
+        if let Some(undo_point) = undo_point {
+            // Another player acting makes everything before it final.
+            if self
+                .undo_points
+                .last()
+                .is_some_and(|last| last.player_idx != undo_point.player_idx)
+            {
+                self.undo_points.clear();
+            }
+            self.undo_points.push(undo_point);
         }
     }
 
@@ -368,7 +1081,14 @@ impl<'s> StratoGame<'s> {
     }
 }
 
+impl Default for StratoGame<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameState {
     /// In the waiting room for players to join.
     #[default]
@@ -385,27 +1105,144 @@ pub enum GameState {
     Ended,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameContext {
     pub players: Vec<Player>,
     pub current_player_idx: Option<usize>,
     pub deck: Deck,
     pub discard_pile: DiscardPile,
 
-    /// How many times the full players list has been iterated through.
+    /// The round being played, counting from 1 once the first turn begins.
     round: usize,
+    /// The turn being played, counting from 1 across every round.
+    turn: usize,
+    /// Index of the player who takes the first turn of each round.
+    pub(crate) starting_player_idx: Option<usize>,
+    /// Every turn that has been completed, oldest first.
+    turns: Vec<TurnRecord>,
     /// Index of the player who finished their spread first, starting the LastRound.
-    finisher_idx: Option<usize>,
-    /// Index of the player who won the game.
-    winner_idx: Option<usize>,
+    pub(crate) finisher_idx: Option<usize>,
+    /// Index of the player whose turn ends the game, once the LastRound has started.
+    pub(crate) last_player_idx: Option<usize>,
+    /// Final standings, filled in when the game ends.
+    result: Option<GameResult>,
+    /// Indexes of the players tied for the highest score while determining the first player.
+    pub(crate) tied_for_first: Vec<usize>,
+    /// How many extra cards the tied players have had to flip so far.
+    tie_break_flips: usize,
+    /// Each player's score for the hand, in seat order. Filled in when the game ends.
+    scores: Vec<PlayerScore>,
+}
+
+/// What happened during one completed turn.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TurnRecord {
+    pub turn: usize,
+    pub round: usize,
+    pub player_id: String,
+    pub start: StartAction,
+    pub end: EndAction,
+    /// The card that was drawn or taken from the discard pile.
+    pub card: CardValue,
+}
+
+/// How a player's score for the hand was worked out.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerScore {
+    pub player_id: String,
+    /// Sum of every card left in the player's spread.
+    pub spread_score: i32,
+    /// Points added by the finisher penalty (0 if it didn't apply).
+    pub penalty: i32,
+    pub final_score: i32,
 }
 
+/// Something that happened in the game, in the order it happened.
+/// Cards that are still hidden from the rest of the table are never revealed.
 #[derive(Debug, Clone, PartialEq)]
-pub enum GameEvent<'a> {
-    StateChange(&'a GameState),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameEvent {
+    StateChange(GameState),
+    PlayerJoined {
+        player_id: String,
+        name: String,
+    },
+    /// The player was removed from the lobby, or forfeited a game in progress.
+    PlayerLeft {
+        player_id: String,
+    },
+    /// The players took their seats for the game, listed in turn order.
+    PlayersSeated {
+        player_ids: Vec<String>,
+    },
+    /// Every player was dealt their spread, and the first card was turned up on the discard pile.
+    CardsDealt {
+        cards_per_player: usize,
+        discard_pile_top: CardValue,
+    },
+    /// A card in a player's spread was turned face-up.
+    CardFlipped {
+        player_id: String,
+        row: usize,
+        column: usize,
+        value: CardValue,
+    },
+    /// A player drew from the deck. Only they get to see the card.
+    CardDrawn {
+        player_id: String,
+    },
+    CardTakenFromDiscard {
+        player_id: String,
+        value: CardValue,
+    },
+    /// A player put the card they were holding into their spread, discarding the one it replaced.
+    CardSwapped {
+        player_id: String,
+        row: usize,
+        column: usize,
+        placed: CardValue,
+        discarded: CardValue,
+    },
+    /// A player discarded the card they were holding.
+    CardDiscarded {
+        player_id: String,
+        value: CardValue,
+    },
+    /// A line of matching cards was removed from a player's spread.
+    LineCleared {
+        player_id: String,
+        line: Line,
+        cards: Vec<CardValue>,
+    },
+    /// It is now this player's turn.
+    TurnAdvanced {
+        player_id: String,
+    },
+    /// The player flipped their last card, so everyone else gets one more turn.
+    LastRoundTriggered {
+        player_id: String,
+    },
+    GameEnded {
+        result: GameResult,
+    },
+    /// The deck ran out, so this many cards from the discard pile were shuffled back into it.
+    DeckReshuffled {
+        cards: usize,
+    },
+    /// The player's last turn action was taken back.
+    TurnUndone {
+        player_id: String,
+    },
 }
 
-struct Subscriber<'s>(Box<dyn Fn(GameEvent) + 's>);
+/// Identifies a subscription, so it can be removed with `StratoGame::unsubscribe`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+struct Subscriber<'s>(Box<dyn Fn(GameEvent) + Send + Sync + 's>);
 
 impl std::fmt::Debug for Subscriber<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -414,20 +1251,220 @@ impl std::fmt::Debug for Subscriber<'_> {
 }
 
 impl<'s> Subscriber<'s> {
-    fn new<F: Fn(GameEvent) + 's>(f: F) -> Self {
+    fn new<F: Fn(GameEvent) + Send + Sync + 's>(f: F) -> Self {
         Self(Box::new(f))
     }
 }
 
-#[derive(Default, Debug)]
+/// The game as it was just before a turn action, so it can be restored.
+#[derive(Debug, Clone)]
+struct UndoPoint {
+    player_idx: usize,
+    state: GameState,
+    context: GameContext,
+    rng: ChaCha8Rng,
+    actions_len: usize,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameOptions {
+    /// The seat of the player who goes first. Like every player index in these options, it
+    /// refers to the order players are in after `seating`, so it can't be used with
+    /// `Seating::Random`.
     pub first_player_idx: Option<usize>,
+    /// Re-seed the game's randomness when it starts, making the deal reproducible.
+    /// Player IDs are generated before this, so use `StratoGame::with_seed` to fix those too.
+    pub seed: Option<u64>,
+    pub first_player_tie_break: FirstPlayerTieBreak,
+    /// How many standard decks to combine, for large tables.
+    /// Defaults to one deck for every `StratoGame::DEFAULT_MAX_PLAYERS` players, or more if
+    /// that isn't enough to deal every spread at the rules' spread size.
+    pub deck_sets: Option<usize>,
+    /// The house rules to play by.
+    pub rules: Rules,
+    /// Let players take back their last turn actions with `StratoGame::undo`.
+    pub allow_undo: bool,
+    pub seating: Seating,
+}
+
+/// The order players are seated in, and so take their turns in.
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Seating {
+    /// In the order they joined the game.
+    #[default]
+    JoinOrder,
+    /// Shuffled when the game starts.
+    Random,
+    /// Player IDs in the order the host chose. Every player must be listed exactly once.
+    Explicit(Vec<String>),
+}
+
+/// How to settle a tie for the highest score while determining the first player.
+#[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FirstPlayerTieBreak {
+    /// The tied players each flip one more card, until only one of them is highest.
+    #[default]
+    FlipAnotherCard,
+    /// One of the tied players is picked at random.
+    Random,
+    /// The youngest of the tied players goes first. Player indexes, from youngest to oldest.
+    Youngest(Vec<usize>),
 }
 
-fn last_player_idx(players_count: usize, finisher_idx: usize) -> usize {
-    if finisher_idx == 0 {
-        players_count - 1
+/// Final standings of a game. Lowest score wins.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameResult {
+    /// Every player, ordered from first place to last. Ties keep seat order.
+    pub standings: Vec<Standing>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Standing {
+    /// 1-based. Tied players share a place, and the places after them are skipped (1, 1, 3).
+    pub place: usize,
+    pub player_idx: usize,
+    pub player_id: String,
+    pub final_score: i32,
+}
+
+impl GameResult {
+    fn from_scores(scores: &[PlayerScore]) -> Self {
+        let mut ranked = scores.iter().enumerate().collect::<Vec<_>>();
+        ranked.sort_by_key(|(_, s)| s.final_score);
+
+        let standings = ranked
+            .iter()
+            .map(|(player_idx, score)| Standing {
+                place: 1 + ranked
+                    .iter()
+                    .filter(|(_, s)| s.final_score < score.final_score)
+                    .count(),
+                player_idx: *player_idx,
+                player_id: score.player_id.clone(),
+                final_score: score.final_score,
+            })
+            .collect();
+
+        Self { standings }
+    }
+
+    /// Everyone in first place. More than one when the lowest score is tied.
+    pub fn winners(&self) -> Vec<&Standing> {
+        self.standings.iter().filter(|s| s.place == 1).collect()
+    }
+
+    pub fn is_tie(&self) -> bool {
+        self.winners().len() > 1
+    }
+
+    pub fn standing_for<S: Into<String> + Clone>(&self, player_id: S) -> Option<&Standing> {
+        self.standings
+            .iter()
+            .find(|s| s.player_id == player_id.clone().into())
+    }
+}
+
+/// The player who went out has their score doubled, unless it was strictly the lowest or not
+/// positive. Returns the points to add.
+fn finisher_penalty(spread_scores: &[i32], finisher_idx: usize) -> i32 {
+    let finisher_score = spread_scores[finisher_idx];
+    let strictly_lowest = spread_scores
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != finisher_idx)
+        .all(|(_, score)| finisher_score < *score);
+
+    if finisher_score > 0 && !strictly_lowest {
+        finisher_score
+    } else {
+        0
+    }
+}
+
+/// One deck for every `StratoGame::DEFAULT_MAX_PLAYERS` players, or more if the spreads
+/// need them.
+fn deck_sets_for(players_count: usize, cards_needed: usize, deck_size: usize) -> usize {
+    let for_players = players_count.div_ceil(StratoGame::DEFAULT_MAX_PLAYERS);
+    // An empty deck can't be made big enough, which is caught as `DeckTooSmall`.
+    let for_cards = if deck_size == 0 {
+        0
     } else {
-        finisher_idx - 1
+        cards_needed.div_ceil(deck_size)
+    };
+    for_players.max(for_cards).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scores_of(final_scores: &[i32]) -> Vec<PlayerScore> {
+        final_scores
+            .iter()
+            .enumerate()
+            .map(|(idx, score)| PlayerScore {
+                player_id: idx.to_string(),
+                spread_score: *score,
+                penalty: 0,
+                final_score: *score,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lowest_score_wins() {
+        let result = GameResult::from_scores(&scores_of(&[22, 5, 13]));
+        let winners = result.winners();
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].player_idx, 1);
+        assert!(!result.is_tie());
+
+        let order = result
+            .standings
+            .iter()
+            .map(|s| (s.player_idx, s.place))
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![(1, 1), (2, 2), (0, 3)]);
+    }
+
+    #[test]
+    fn tied_players_share_a_place() {
+        let result = GameResult::from_scores(&scores_of(&[10, 5, 10, 5, 30]));
+        assert!(result.is_tie());
+
+        let order = result
+            .standings
+            .iter()
+            .map(|s| (s.player_idx, s.place))
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![(1, 1), (3, 1), (0, 3), (2, 3), (4, 5)]);
+        assert_eq!(result.standing_for("4").unwrap().place, 5);
+    }
+
+    #[test]
+    fn finisher_with_strictly_lowest_score_is_not_penalized() {
+        assert_eq!(finisher_penalty(&[5, 12, 20], 0), 0);
+    }
+
+    #[test]
+    fn finisher_without_lowest_score_is_doubled() {
+        assert_eq!(finisher_penalty(&[15, 12, 20], 0), 15);
+        assert_eq!(finisher_penalty(&[12, 20, 4], 1), 20);
+    }
+
+    #[test]
+    fn finisher_tied_for_lowest_is_doubled() {
+        assert_eq!(finisher_penalty(&[12, 12], 1), 12);
+    }
+
+    #[test]
+    fn finisher_with_non_positive_score_is_never_doubled() {
+        assert_eq!(finisher_penalty(&[0, -4], 0), 0);
+        assert_eq!(finisher_penalty(&[-2, -4], 0), 0);
     }
 }
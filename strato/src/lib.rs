@@ -1,3 +1,9 @@
 pub mod card;
+pub mod command;
 pub mod game;
+pub mod match_play;
 pub mod player;
+pub mod replay;
+pub mod rules;
+pub mod snapshot;
+pub mod view;
@@ -0,0 +1,196 @@
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::game::{GameOptions, GameStartupError, GameState, StratoGame};
+use crate::rules::Rules;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MatchError {
+    #[error("The current hand is still being played.")]
+    HandNotOver,
+    #[error("The match is already over.")]
+    MatchOver,
+    #[error(transparent)]
+    GameStartupError(#[from] GameStartupError),
+}
+
+/// A series of hands played until someone's total score crosses the threshold. Lowest total wins.
+#[derive(Debug, Clone)]
+pub struct Match<'s> {
+    /// The hand currently being played (or the last one, once the match is over).
+    game: StratoGame<'s>,
+    /// The rules every hand is played by, including the score that ends the match.
+    rules: Rules,
+    /// Running totals for everyone in the match, in seat order.
+    scores: Vec<PlayerTotal>,
+    /// How many hands have been scored so far.
+    hands_played: usize,
+    /// IDs of the players with the lowest total once the match is over.
+    winner_ids: Option<Vec<String>>,
+}
+
+/// A player's score for every hand of the match so far.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerTotal {
+    pub player_id: String,
+    /// Score of each hand, in the order they were played.
+    pub hands: Vec<i32>,
+    pub total: i32,
+    /// Whether the player forfeited. Their total stops counting and they can't win the match.
+    pub forfeited: bool,
+}
+
+impl<'s> Match<'s> {
+    /// The score at which a traditional match ends.
+    pub const DEFAULT_THRESHOLD: i32 = 100;
+
+    pub fn new() -> Self {
+        Self::with_threshold(Self::DEFAULT_THRESHOLD)
+    }
+
+    pub fn with_threshold(threshold: i32) -> Self {
+        Self::with_rules(Rules {
+            score_threshold: threshold,
+            ..Rules::default()
+        })
+    }
+
+    pub fn with_rules(rules: Rules) -> Self {
+        Self {
+            game: StratoGame::new(),
+            rules,
+            scores: vec![],
+            hands_played: 0,
+            winner_ids: None,
+        }
+    }
+
+    pub fn add_player<S: Into<String>>(
+        &mut self,
+        player_name: S,
+    ) -> Result<String, GameStartupError> {
+        let player_id = self.game.add_player(player_name)?;
+
+        self.scores.push(PlayerTotal {
+            player_id: player_id.clone(),
+            hands: vec![],
+            total: 0,
+            forfeited: false,
+        });
+
+        Ok(player_id)
+    }
+
+    /// Start the first hand. Unless the rules skip it, players flip cards to determine who
+    /// goes first.
+    pub fn start(&mut self) -> Result<(), GameStartupError> {
+        self.game.start_with_options(GameOptions {
+            rules: self.rules.clone(),
+            ..GameOptions::default()
+        })
+    }
+
+    /// The hand currently being played.
+    pub fn game(&self) -> &StratoGame<'s> {
+        &self.game
+    }
+
+    /// The hand currently being played, for taking turns.
+    pub fn game_mut(&mut self) -> &mut StratoGame<'s> {
+        &mut self.game
+    }
+
+    pub fn threshold(&self) -> i32 {
+        self.rules.score_threshold
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Number of the hand currently being played, starting at 1.
+    pub fn hand_number(&self) -> usize {
+        if self.is_over() {
+            self.hands_played
+        } else {
+            self.hands_played + 1
+        }
+    }
+
+    pub fn scores(&self) -> &[PlayerTotal] {
+        &self.scores
+    }
+
+    pub fn total_for<S: Into<String> + Clone>(&self, player_id: S) -> Option<i32> {
+        self.scores
+            .iter()
+            .find(|s| s.player_id == player_id.clone().into())
+            .map(|s| s.total)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner_ids.is_some()
+    }
+
+    /// IDs of the players with the lowest total, once the match is over. More than one on a tie.
+    pub fn winner_ids(&self) -> Option<&[String]> {
+        self.winner_ids.as_deref()
+    }
+
+    /// Score the hand that just ended and add it to everyone's total. If nobody has crossed the
+    /// threshold, the next hand is dealt and started with the previous finisher going first.
+    /// Players who forfeited the hand are out of the match, which ends once fewer than two remain.
+    pub fn finish_hand(&mut self) -> Result<(), MatchError> {
+        if self.is_over() {
+            return Err(MatchError::MatchOver);
+        }
+        if self.game.state != GameState::Ended {
+            return Err(MatchError::HandNotOver);
+        }
+
+        let hand_scores = self.game.scores().unwrap_or_default();
+        for score in self.scores.iter_mut().filter(|s| !s.forfeited) {
+            match hand_scores.iter().find(|h| h.player_id == score.player_id) {
+                Some(hand_score) => {
+                    score.hands.push(hand_score.final_score);
+                    score.total += hand_score.final_score;
+                }
+                None => score.forfeited = true,
+            }
+        }
+        self.hands_played += 1;
+
+        let remaining = self
+            .scores
+            .iter()
+            .filter(|s| !s.forfeited)
+            .collect::<Vec<_>>();
+        if remaining.len() < 2 || remaining.iter().any(|s| s.total >= self.threshold()) {
+            let lowest_total = remaining.iter().map(|s| s.total).min();
+            let winner_ids = remaining
+                .iter()
+                .filter(|s| Some(s.total) == lowest_total)
+                .map(|s| s.player_id.clone())
+                .collect();
+            self.winner_ids = Some(winner_ids);
+            return Ok(());
+        }
+
+        let finisher_idx = self.game.finisher_idx();
+        self.game = self.game.next_hand()?;
+        self.game.start_with_options(GameOptions {
+            first_player_idx: finisher_idx,
+            rules: self.rules.clone(),
+            ..GameOptions::default()
+        })?;
+
+        Ok(())
+    }
+}
+
+impl Default for Match<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -9,24 +9,32 @@ pub enum PlayerActionError {
 }
 
 #[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
     // TODO: Use private key for auth?
     /// A generated identifier.
     id: String,
     /// The player's chosen name or alias.
-    name: &'static str,
+    name: String,
     /// The card the user has in-hand after drawing from the deck or taking from the discard pile.
     holding: Option<Card>,
-    /// The grid of cards that each player has. Starts as 4x3 and may shrink as columns match.
+    /// Where the card in-hand came from, since that limits how the turn may end.
+    held_from: Option<StartAction>,
+    /// The grid of cards that each player has. Starts at the rules' spread size and may shrink
+    /// as matching lines are cleared.
     pub spread: PlayerSpread,
 }
 
 impl Player {
-    pub fn new(id: String, name: &'static str) -> Self {
+    /// The longest name a player can have, in characters.
+    pub const MAX_NAME_LENGTH: usize = 20;
+
+    pub fn new(id: String, name: String) -> Self {
         Self {
             id,
             name,
             holding: None,
+            held_from: None,
             spread: PlayerSpread::new(),
         }
     }
@@ -36,7 +44,7 @@ impl Player {
     }
 
     pub fn name(&self) -> String {
-        self.name.to_string()
+        self.name.clone()
     }
 
     /// View what the player is holding, if anything.
@@ -44,34 +52,43 @@ impl Player {
         self.holding
     }
 
+    /// How the player got the card they are holding, if anything.
+    pub fn held_from(&self) -> Option<StartAction> {
+        self.held_from
+    }
+
     /// The Game gives the player the card they drew or took during the start of their
     /// turn, to use when they end their turn.
-    pub fn hold(&mut self, mut card: Card) -> Result<(), PlayerActionError> {
+    pub fn hold(&mut self, mut card: Card, from: StartAction) -> Result<(), PlayerActionError> {
         if let Some(card_in_hand) = self.holding {
             return Err(PlayerActionError::AlreadyHoldingCard(card_in_hand));
         }
 
         card.flip();
         self.holding = Some(card);
+        self.held_from = Some(from);
 
         Ok(())
     }
 
     /// The Game requests the card the player is holding.
     pub fn release(&mut self) -> Option<Card> {
+        self.held_from = None;
         self.holding.take()
     }
 }
 
 /// The way the player chooses to start their turn.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StartAction {
     DrawFromDeck,
     TakeFromDiscardPile,
 }
 
 /// The way the player chooses to end their turn.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EndAction {
     /// Row and Column are 0-based.
     Swap { row: usize, column: usize },
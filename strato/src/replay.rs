@@ -0,0 +1,120 @@
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::command::PlayerCommand;
+use crate::game::{GameOptions, GameStartupError, PlayerTurnError, StratoGame};
+use crate::player::{EndAction, StartAction};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReplayError {
+    #[error("Action #{0} couldn't be replayed: {1}")]
+    GameStartupError(usize, #[source] GameStartupError),
+    #[error("Action #{0} couldn't be replayed: {1}")]
+    PlayerTurnError(usize, #[source] PlayerTurnError),
+}
+
+/// A command the game accepted, as recorded in its action log.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    AddPlayer {
+        name: String,
+    },
+    RemovePlayer {
+        player_id: String,
+    },
+    /// Players carried over from a previous hand, as their ID and name, in seat order.
+    SeatPlayers {
+        players: Vec<(String, String)>,
+    },
+    SetMaxPlayers {
+        max_players: usize,
+    },
+    Start {
+        options: GameOptions,
+    },
+    FlipToDetermineFirstPlayer {
+        player_id: String,
+        row: usize,
+        column: usize,
+    },
+    StartTurn {
+        player_id: String,
+        action: StartAction,
+    },
+    EndTurn {
+        player_id: String,
+        action: EndAction,
+    },
+    Forfeit {
+        player_id: String,
+    },
+}
+
+impl<'s> StratoGame<'s> {
+    /// Every command the game has accepted so far, in the order they happened.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Rebuild a game from the seed it was created with and the actions it accepted.
+    pub fn replay(seed: u64, actions: &[Action]) -> Result<Self, ReplayError> {
+        let mut game = Self::with_seed(seed);
+
+        for (idx, action) in actions.iter().enumerate() {
+            match action.clone() {
+                Action::AddPlayer { name } => {
+                    game.add_player(name)
+                        .map_err(|e| ReplayError::GameStartupError(idx, e))?;
+                }
+                Action::RemovePlayer { player_id } => game
+                    .remove_player(player_id)
+                    .map_err(|e| ReplayError::GameStartupError(idx, e))?,
+                Action::SeatPlayers { players } => game
+                    .seat_returning_players(players)
+                    .map_err(|e| ReplayError::GameStartupError(idx, e))?,
+                Action::SetMaxPlayers { max_players } => game
+                    .set_max_players(max_players)
+                    .map_err(|e| ReplayError::GameStartupError(idx, e))?,
+                Action::Start { options } => game
+                    .start_with_options(options)
+                    .map_err(|e| ReplayError::GameStartupError(idx, e))?,
+                Action::FlipToDetermineFirstPlayer {
+                    player_id,
+                    row,
+                    column,
+                } => replay_command(
+                    &mut game,
+                    idx,
+                    player_id,
+                    PlayerCommand::FlipToDetermineFirstPlayer { row, column },
+                )?,
+                Action::StartTurn { player_id, action } => replay_command(
+                    &mut game,
+                    idx,
+                    player_id,
+                    PlayerCommand::StartTurn { action },
+                )?,
+                Action::EndTurn { player_id, action } => {
+                    replay_command(&mut game, idx, player_id, PlayerCommand::EndTurn { action })?
+                }
+                Action::Forfeit { player_id } => {
+                    replay_command(&mut game, idx, player_id, PlayerCommand::Forfeit)?
+                }
+            }
+        }
+
+        Ok(game)
+    }
+}
+
+fn replay_command(
+    game: &mut StratoGame,
+    idx: usize,
+    player_id: String,
+    command: PlayerCommand,
+) -> Result<(), ReplayError> {
+    game.apply(player_id, command)
+        .map(|_| ())
+        .map_err(|e| ReplayError::PlayerTurnError(idx, e))
+}
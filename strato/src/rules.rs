@@ -0,0 +1,52 @@
+use crate::card::{DeckComposition, MatchRule, SpreadSize};
+use crate::match_play::Match;
+
+/// Every house rule the engine follows. Pass it in `GameOptions`, or to `Match::with_rules`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rules {
+    /// Which cards make up a deck.
+    pub deck_composition: DeckComposition,
+    /// How many rows and columns each player is dealt, e.g. 3×3 for a quick game.
+    pub spread_size: SpreadSize,
+    /// Which lines of matching cards are cleared.
+    pub match_rule: MatchRule,
+    /// Double the finisher's score unless it was strictly the lowest.
+    pub finisher_penalty: bool,
+    /// A match ends once someone's total reaches this.
+    pub score_threshold: i32,
+    /// Everyone flips two cards to decide who goes first. Otherwise, the first player is
+    /// picked at random.
+    pub initial_flip: bool,
+    /// A card drawn from the deck may be discarded to flip a card in the spread instead.
+    pub discard_then_flip: bool,
+}
+
+impl Rules {
+    /// The rules as printed, including the official mix of cards.
+    pub fn official() -> Self {
+        Self {
+            deck_composition: DeckComposition::official(),
+            ..Self::family()
+        }
+    }
+
+    /// The rules this engine has always played by: the official ones, with ten of every card.
+    pub fn family() -> Self {
+        Self {
+            deck_composition: DeckComposition::uniform(),
+            spread_size: SpreadSize::default(),
+            match_rule: MatchRule::default(),
+            finisher_penalty: true,
+            score_threshold: Match::DEFAULT_THRESHOLD,
+            initial_flip: true,
+            discard_then_flip: true,
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::family()
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use rand_chacha::ChaCha8Rng;
+use thiserror::Error;
+
+use crate::card::count_values;
+use crate::game::{FirstPlayerTieBreak, GameContext, GameOptions, GameState, StratoGame};
+use crate::replay::Action;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LoadError {
+    #[error("Snapshot version {0} is not supported.")]
+    UnsupportedVersion(u32),
+    #[error("Found {found} cards with a value of {value}, but the deck only has {allowed}.")]
+    TooManyCards {
+        value: i32,
+        found: usize,
+        allowed: usize,
+    },
+    #[error("More than one player has the ID {0}.")]
+    DuplicatePlayer(String),
+    #[error("Not enough players for a game that has started.")]
+    NotEnoughPlayers,
+    #[error("More players than the game allows.")]
+    TooManyPlayers,
+    #[error("The current player doesn't exist.")]
+    CurrentPlayerDoesntExist,
+    #[error("The player who starts each round doesn't exist.")]
+    StartingPlayerDoesntExist,
+    #[error("A player tied for going first doesn't exist.")]
+    TiedPlayerDoesntExist,
+    #[error(
+        "Players can only be tied for going first while the first player is being determined."
+    )]
+    UnexpectedTieForFirst,
+    #[error("A player in the youngest-to-oldest order doesn't exist.")]
+    YoungestPlayerDoesntExist,
+    #[error("The player who finished doesn't exist.")]
+    FinisherDoesntExist,
+    #[error("The player who takes the last turn doesn't exist.")]
+    LastPlayerDoesntExist,
+    #[error("The last round can't happen without knowing who takes the last turn.")]
+    MissingLastPlayer,
+    #[error("Only the current player can be holding a card.")]
+    CardHeldOutOfTurn,
+    #[error("A held card must be recorded along with where it came from.")]
+    HeldCardWithoutSource,
+    #[error("A player's spread doesn't match the size the game was dealt with.")]
+    WrongSpreadSize,
+}
+
+/// Everything needed to pick a game back up exactly where it left off.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+    version: u32,
+    state: GameState,
+    context: GameContext,
+    options: GameOptions,
+    seed: u64,
+    rng: ChaCha8Rng,
+    actions: Vec<Action>,
+    max_players: usize,
+}
+
+impl GameSnapshot {
+    /// Bumped whenever the layout of a snapshot changes.
+    pub const VERSION: u32 = 1;
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Reject snapshots describing a game that couldn't have happened.
+    fn validate(&self) -> Result<(), LoadError> {
+        if self.version != Self::VERSION {
+            return Err(LoadError::UnsupportedVersion(self.version));
+        }
+
+        let context = &self.context;
+
+        let held_cards = context.players.iter().filter_map(|p| p.holding());
+        let all_cards = context
+            .deck
+            .cards()
+            .chain(context.discard_pile.cards())
+            .chain(
+                context
+                    .players
+                    .iter()
+                    .flat_map(|p| p.spread.remaining_cards()),
+            )
+            .copied()
+            .chain(held_cards)
+            .collect::<Vec<_>>();
+        let found_counts = count_values(&all_cards);
+        let deck_sets = self.options.deck_sets.unwrap_or(1);
+        for (value, found) in found_counts {
+            let allowed = self.options.rules.deck_composition.count(value) * deck_sets;
+            if found > allowed {
+                return Err(LoadError::TooManyCards {
+                    value: i32::from(value),
+                    found,
+                    allowed,
+                });
+            }
+        }
+
+        let mut player_ids = HashSet::new();
+        for player in context.players.iter() {
+            if !player_ids.insert(player.id()) {
+                return Err(LoadError::DuplicatePlayer(player.id()));
+            }
+        }
+
+        // A game that has ended may have been won by forfeit.
+        let needs_players = !matches!(self.state, GameState::WaitingForPlayers | GameState::Ended);
+        if needs_players && context.players.len() < 2 {
+            return Err(LoadError::NotEnoughPlayers);
+        }
+        if context.players.len() > self.max_players {
+            return Err(LoadError::TooManyPlayers);
+        }
+
+        let in_turns = matches!(self.state, GameState::Active | GameState::LastRound);
+        match context.current_player_idx {
+            Some(idx) if idx >= context.players.len() => {
+                return Err(LoadError::CurrentPlayerDoesntExist)
+            }
+            None if in_turns => return Err(LoadError::CurrentPlayerDoesntExist),
+            _ => {}
+        }
+
+        match context.starting_player_idx {
+            Some(idx) if idx >= context.players.len() => {
+                return Err(LoadError::StartingPlayerDoesntExist)
+            }
+            None if in_turns => return Err(LoadError::StartingPlayerDoesntExist),
+            _ => {}
+        }
+
+        if context
+            .tied_for_first
+            .iter()
+            .any(|idx| *idx >= context.players.len())
+        {
+            return Err(LoadError::TiedPlayerDoesntExist);
+        }
+        if self.state != GameState::DetermineFirstPlayer && !context.tied_for_first.is_empty() {
+            return Err(LoadError::UnexpectedTieForFirst);
+        }
+        if let FirstPlayerTieBreak::Youngest(youngest_to_oldest) =
+            &self.options.first_player_tie_break
+        {
+            if youngest_to_oldest
+                .iter()
+                .any(|idx| *idx >= context.players.len())
+            {
+                return Err(LoadError::YoungestPlayerDoesntExist);
+            }
+        }
+
+        // The finisher may have forfeited since, so only the last player is required.
+        if matches!(context.finisher_idx, Some(idx) if idx >= context.players.len()) {
+            return Err(LoadError::FinisherDoesntExist);
+        }
+        match context.last_player_idx {
+            Some(idx) if idx >= context.players.len() => {
+                return Err(LoadError::LastPlayerDoesntExist)
+            }
+            None if self.state == GameState::LastRound => return Err(LoadError::MissingLastPlayer),
+            _ => {}
+        }
+
+        let dealt = !matches!(
+            self.state,
+            GameState::WaitingForPlayers | GameState::Startup
+        );
+        if dealt
+            && context
+                .players
+                .iter()
+                .any(|p| !p.spread.has_size(self.options.rules.spread_size))
+        {
+            return Err(LoadError::WrongSpreadSize);
+        }
+
+        for (idx, player) in context.players.iter().enumerate() {
+            let is_their_turn = in_turns && context.current_player_idx == Some(idx);
+            let holding = player.holding().is_some() || player.held_from().is_some();
+            if holding && !is_their_turn {
+                return Err(LoadError::CardHeldOutOfTurn);
+            }
+            if player.holding().is_some() != player.held_from().is_some() {
+                return Err(LoadError::HeldCardWithoutSource);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'s> StratoGame<'s> {
+    /// Capture the whole game, including the order of the deck and every hidden card.
+    /// Subscribers and undo history are not part of the snapshot.
+    pub fn save(&self) -> GameSnapshot {
+        GameSnapshot {
+            version: GameSnapshot::VERSION,
+            state: self.state.clone(),
+            context: self.context.clone(),
+            options: self.options.clone(),
+            seed: self.seed,
+            rng: self.rng.clone(),
+            actions: self.actions.clone(),
+            max_players: self.max_players,
+        }
+    }
+
+    /// Restore a saved game, after checking that it describes a possible game.
+    pub fn load(snapshot: GameSnapshot) -> Result<Self, LoadError> {
+        snapshot.validate()?;
+
+        let mut game = Self::with_seed(snapshot.seed);
+        game.state = snapshot.state;
+        game.context = snapshot.context;
+        game.options = snapshot.options;
+        game.rng = snapshot.rng;
+        game.actions = snapshot.actions;
+        game.max_players = snapshot.max_players;
+
+        Ok(game)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::card::{CardValue, Spot};
+use crate::game::{GameResult, GameState, StratoGame, TurnRecord};
+use crate::player::Player;
+
+/// Everything one player is allowed to see. Safe to send to that player's client.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerView {
+    pub state: GameState,
+    pub player_id: String,
+    /// Your own spread. Cards you haven't flipped stay face-down, even to you.
+    pub spread: Vec<Vec<Spot>>,
+    /// The card you drew or took this turn.
+    pub holding: Option<CardValue>,
+    /// Everyone else, in seat order.
+    pub opponents: Vec<PublicPlayerView>,
+    pub discard_pile_top: Option<CardValue>,
+    pub deck_size: usize,
+    pub current_player_id: Option<String>,
+    pub is_your_turn: bool,
+    pub round: usize,
+    pub turn: usize,
+    /// What happened on the turn before this one.
+    pub last_turn: Option<TurnRecord>,
+    /// Final standings, once the game has ended.
+    pub result: Option<GameResult>,
+}
+
+/// Everything anyone at the table can see. Safe to broadcast to spectators.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpectatorView {
+    pub state: GameState,
+    /// All players, in seat order.
+    pub players: Vec<PublicPlayerView>,
+    pub discard_pile_top: Option<CardValue>,
+    pub deck_size: usize,
+    pub current_player_id: Option<String>,
+    pub round: usize,
+    pub turn: usize,
+    /// What happened on the turn before this one.
+    pub last_turn: Option<TurnRecord>,
+    /// Final standings, once the game has ended.
+    pub result: Option<GameResult>,
+}
+
+/// What the rest of the table can see of a player.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublicPlayerView {
+    pub player_id: String,
+    pub name: String,
+    pub spread: Vec<Vec<Spot>>,
+    /// Whether they are holding a card, without revealing it.
+    pub is_holding: bool,
+}
+
+impl From<&Player> for PublicPlayerView {
+    fn from(player: &Player) -> Self {
+        Self {
+            player_id: player.id(),
+            name: player.name(),
+            spread: player.spread.spots(),
+            is_holding: player.holding().is_some(),
+        }
+    }
+}
+
+impl<'s> StratoGame<'s> {
+    /// The game as seen by one player, hiding every card they aren't allowed to know.
+    pub fn view_for<S: Into<String> + Clone>(&self, player_id: S) -> Option<PlayerView> {
+        let player = self.get_player(player_id)?;
+        let current_player_id = self.current_player_id();
+
+        Some(PlayerView {
+            state: self.state.clone(),
+            player_id: player.id(),
+            spread: player.spread.spots(),
+            holding: player.holding().and_then(|c| c.get_value()),
+            opponents: self
+                .context
+                .players
+                .iter()
+                .filter(|p| p.id() != player.id())
+                .map(PublicPlayerView::from)
+                .collect(),
+            discard_pile_top: self.discard_pile_top(),
+            deck_size: self.context.deck.size(),
+            is_your_turn: current_player_id == Some(player.id()),
+            current_player_id,
+            round: self.round(),
+            turn: self.turn(),
+            last_turn: self.turns().last().cloned(),
+            result: self.result().cloned(),
+        })
+    }
+
+    /// The game as seen by someone watching, who can't see any player's hidden or held cards.
+    pub fn spectator_view(&self) -> SpectatorView {
+        SpectatorView {
+            state: self.state.clone(),
+            players: self
+                .context
+                .players
+                .iter()
+                .map(PublicPlayerView::from)
+                .collect(),
+            discard_pile_top: self.discard_pile_top(),
+            deck_size: self.context.deck.size(),
+            current_player_id: self.current_player_id(),
+            round: self.round(),
+            turn: self.turn(),
+            last_turn: self.turns().last().cloned(),
+            result: self.result().cloned(),
+        }
+    }
+
+    fn current_player_id(&self) -> Option<String> {
+        self.context
+            .current_player_idx
+            .and_then(|idx| self.context.players.get(idx))
+            .map(Player::id)
+    }
+
+    fn discard_pile_top(&self) -> Option<CardValue> {
+        self.context
+            .discard_pile
+            .top()
+            .and_then(|card| card.get_value())
+    }
+}
@@ -0,0 +1,267 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use strato::{
+    self,
+    command::PlayerCommand,
+    game::{FirstPlayerTieBreak, GameEvent, GameOptions, GameState, PlayerTurnError, StratoGame},
+    player::{EndAction, StartAction},
+};
+
+use common::start_two_player_game;
+
+#[test]
+fn a_turn_can_be_played_through_commands() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    let events = game
+        .apply(
+            &player_1_id,
+            PlayerCommand::StartTurn {
+                action: StartAction::DrawFromDeck,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![GameEvent::CardDrawn {
+            player_id: player_1_id.clone()
+        }]
+    );
+
+    let events = game
+        .apply(
+            &player_1_id,
+            PlayerCommand::EndTurn {
+                action: EndAction::Flip { row: 0, column: 0 },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        events.last(),
+        Some(&GameEvent::TurnAdvanced {
+            player_id: player_2_id
+        })
+    );
+}
+
+#[test]
+fn commands_return_the_same_events_subscribers_get() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+    let received = Arc::new(Mutex::new(vec![]));
+    game.subscribe({
+        let received = received.clone();
+        move |e| received.lock().unwrap().push(e)
+    });
+
+    let mut returned = game
+        .apply(
+            &player_1_id,
+            PlayerCommand::StartTurn {
+                action: StartAction::TakeFromDiscardPile,
+            },
+        )
+        .unwrap();
+    returned.extend(
+        game.apply(
+            &player_1_id,
+            PlayerCommand::EndTurn {
+                action: EndAction::Swap { row: 1, column: 1 },
+            },
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(*received.lock().unwrap(), returned);
+}
+
+#[test]
+fn rejected_commands_change_nothing() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+    let before = game.context.clone();
+
+    let draw = PlayerCommand::StartTurn {
+        action: StartAction::DrawFromDeck,
+    };
+    assert_eq!(
+        game.apply(&player_2_id, draw),
+        Err(PlayerTurnError::NotYourTurn)
+    );
+    assert_eq!(
+        game.apply("nobody", draw),
+        Err(PlayerTurnError::PlayerDoesntExist)
+    );
+    assert_eq!(
+        game.apply(
+            &player_1_id,
+            PlayerCommand::FlipToDetermineFirstPlayer { row: 0, column: 0 }
+        ),
+        Err(PlayerTurnError::NotDeterminingFirstPlayer)
+    );
+
+    assert_eq!(game.context, before);
+    assert_eq!(game.actions().len(), 3);
+}
+
+#[test]
+fn the_first_player_can_be_determined_through_commands() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Cassie").unwrap();
+    let player_2_id = game.add_player("James").unwrap();
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Random,
+        ..Default::default()
+    })
+    .unwrap();
+
+    for player_id in [&player_1_id, &player_2_id] {
+        for row in 0..2 {
+            game.apply(
+                player_id,
+                PlayerCommand::FlipToDetermineFirstPlayer { row, column: 0 },
+            )
+            .unwrap();
+        }
+    }
+
+    assert_eq!(game.state, GameState::Active);
+}
+
+#[test]
+fn a_player_can_forfeit_through_a_command() {
+    let (mut game, _, player_2_id) = start_two_player_game(GameOptions::default());
+
+    let events = game.apply(&player_2_id, PlayerCommand::Forfeit).unwrap();
+
+    assert!(events.contains(&GameEvent::PlayerLeft {
+        player_id: player_2_id
+    }));
+    assert_eq!(game.state, GameState::Ended);
+}
+
+#[test]
+fn nobody_has_legal_actions_in_the_lobby() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Parker").unwrap();
+
+    assert!(game.legal_actions(&player_1_id).is_empty());
+    assert!(game.legal_actions("nobody").is_empty());
+}
+
+#[test]
+fn face_down_cards_can_be_flipped_to_determine_the_first_player() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Cassie").unwrap();
+    game.add_player("James").unwrap();
+    game.start().unwrap();
+
+    assert_eq!(game.legal_actions(&player_1_id).len(), 12);
+
+    game.apply(
+        &player_1_id,
+        PlayerCommand::FlipToDetermineFirstPlayer { row: 0, column: 0 },
+    )
+    .unwrap();
+    let legal_actions = game.legal_actions(&player_1_id);
+    assert_eq!(legal_actions.len(), 11);
+    assert!(
+        !legal_actions.contains(&PlayerCommand::FlipToDetermineFirstPlayer { row: 0, column: 0 })
+    );
+
+    game.apply(
+        &player_1_id,
+        PlayerCommand::FlipToDetermineFirstPlayer { row: 0, column: 1 },
+    )
+    .unwrap();
+    assert!(game.legal_actions(&player_1_id).is_empty());
+}
+
+#[test]
+fn only_the_current_player_can_start_a_turn() {
+    let (game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    assert_eq!(
+        game.legal_actions(&player_1_id),
+        vec![
+            PlayerCommand::StartTurn {
+                action: StartAction::DrawFromDeck
+            },
+            PlayerCommand::StartTurn {
+                action: StartAction::TakeFromDiscardPile
+            },
+        ]
+    );
+    assert!(game.legal_actions(&player_2_id).is_empty());
+}
+
+#[test]
+fn a_drawn_card_can_be_swapped_or_discarded_to_flip() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+    game.context.players[0].spread.flip_at(1, 1).unwrap();
+
+    game.apply(
+        &player_1_id,
+        PlayerCommand::StartTurn {
+            action: StartAction::DrawFromDeck,
+        },
+    )
+    .unwrap();
+    let legal_actions = game.legal_actions(&player_1_id);
+
+    assert_eq!(legal_actions.len(), 12 + 11);
+    assert!(legal_actions.contains(&PlayerCommand::EndTurn {
+        action: EndAction::Swap { row: 1, column: 1 }
+    }));
+    assert!(!legal_actions.contains(&PlayerCommand::EndTurn {
+        action: EndAction::Flip { row: 1, column: 1 }
+    }));
+}
+
+#[test]
+fn a_card_taken_from_the_discard_pile_can_only_be_swapped() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+
+    game.apply(
+        &player_1_id,
+        PlayerCommand::StartTurn {
+            action: StartAction::TakeFromDiscardPile,
+        },
+    )
+    .unwrap();
+    let legal_actions = game.legal_actions(&player_1_id);
+
+    assert_eq!(legal_actions.len(), 12);
+    assert!(legal_actions.iter().all(|c| matches!(
+        c,
+        PlayerCommand::EndTurn {
+            action: EndAction::Swap { .. }
+        }
+    )));
+}
+
+#[test]
+fn a_game_can_be_played_out_with_only_legal_actions() {
+    let mut game = StratoGame::with_seed(12);
+    let player_ids = ["Parker", "Trevor", "Cassie"].map(|name| game.add_player(name).unwrap());
+    game.start().unwrap();
+
+    for _ in 0..1000 {
+        if game.state == GameState::Ended {
+            break;
+        }
+        let (player_id, legal_actions) = player_ids
+            .iter()
+            .map(|id| (id, game.legal_actions(id)))
+            .find(|(_, actions)| !actions.is_empty())
+            .expect("Someone should be able to act");
+        // Drawing and then flipping whenever possible makes sure the game moves along.
+        let command = match legal_actions[0] {
+            PlayerCommand::StartTurn { .. } => legal_actions[0],
+            _ => legal_actions[legal_actions.len() - 1],
+        };
+        game.apply(player_id, command).unwrap();
+    }
+
+    assert_eq!(game.state, GameState::Ended);
+}
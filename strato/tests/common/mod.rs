@@ -0,0 +1,86 @@
+// Each test crate uses a different subset of these helpers.
+#![allow(dead_code)]
+
+use strato::{
+    card::{CardValue, Deck},
+    game::{GameOptions, StratoGame},
+    player::{EndAction, StartAction},
+};
+
+/// Seat the named players and start the game. The first of them goes first, unless the options
+/// pick someone else.
+pub fn seat_and_start<const N: usize>(
+    game: &mut StratoGame,
+    names: [&str; N],
+    options: GameOptions,
+) -> [String; N] {
+    let player_ids = names.map(|name| game.add_player(name).unwrap());
+    game.start_with_options(GameOptions {
+        first_player_idx: options.first_player_idx.or(Some(0)),
+        ..options
+    })
+    .unwrap();
+    player_ids
+}
+
+/// A new game with the named players seated and started, as in [`seat_and_start`].
+pub fn start_game<const N: usize>(
+    names: [&str; N],
+    options: GameOptions,
+) -> (StratoGame<'static>, [String; N]) {
+    let mut game = StratoGame::new();
+    let player_ids = seat_and_start(&mut game, names, options);
+    (game, player_ids)
+}
+
+/// A new game between Parker and Trevor, with Parker going first unless the options say otherwise.
+pub fn start_two_player_game(options: GameOptions) -> (StratoGame<'static>, String, String) {
+    let (game, [player_1_id, player_2_id]) = start_game(["Parker", "Trevor"], options);
+    (game, player_1_id, player_2_id)
+}
+
+/// A seeded game between Parker and Trevor, stopped partway through Trevor's first turn: Parker
+/// has drawn and flipped, and Trevor is holding a card drawn from the deck.
+pub fn game_in_progress(seed: u64) -> (StratoGame<'static>, String, String) {
+    let mut game = StratoGame::with_seed(seed);
+    let [player_1_id, player_2_id] =
+        seat_and_start(&mut game, ["Parker", "Trevor"], GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 1, column: 2 })
+        .unwrap();
+    game.start_player_turn(&player_2_id, StartAction::DrawFromDeck)
+        .unwrap();
+
+    (game, player_1_id, player_2_id)
+}
+
+/// Replace the card at a spot with a face-down card of the given value.
+pub fn rig_card(game: &mut StratoGame, player_idx: usize, row: usize, column: usize, value: i32) {
+    let mut deck = Deck::default();
+    let card = loop {
+        let card = deck.draw().unwrap();
+        let mut peek = card;
+        peek.flip();
+        if peek.get_value() == Some(CardValue::from(value)) {
+            break card;
+        }
+    };
+
+    let spread = &mut game.context.players[player_idx].spread;
+    spread.take_from(row, column).unwrap();
+    spread.place_at(card, row, column).unwrap();
+}
+
+/// Flip every card of a standard spread except the one at `last`.
+pub fn flip_all_but(game: &mut StratoGame, player_idx: usize, last: (usize, usize)) {
+    let spread = &mut game.context.players[player_idx].spread;
+    for row in 0..3 {
+        for column in 0..4 {
+            if (row, column) != last {
+                spread.flip_at(row, column).unwrap();
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use strato::{
+    self,
+    card::{CardValue, Line, MatchLines, MatchRule},
+    game::{GameEvent, GameOptions, GameState, StratoGame},
+    player::{EndAction, StartAction},
+    rules::Rules,
+};
+
+use common::{flip_all_but, rig_card, seat_and_start, start_two_player_game};
+
+fn record_events(game: &mut StratoGame) -> Arc<Mutex<Vec<GameEvent>>> {
+    let events = Arc::new(Mutex::new(vec![]));
+    game.subscribe({
+        let events = events.clone();
+        move |e| events.lock().unwrap().push(e)
+    });
+    events
+}
+
+#[test]
+fn joining_and_starting_are_reported_in_order() {
+    let mut game = StratoGame::new();
+    let events = record_events(&mut game);
+    let [player_1_id, player_2_id] =
+        seat_and_start(&mut game, ["Parker", "Trevor"], GameOptions::default());
+
+    let discard_pile_top = game
+        .context
+        .discard_pile
+        .top()
+        .unwrap()
+        .get_value()
+        .unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            GameEvent::PlayerJoined {
+                player_id: player_1_id.clone(),
+                name: "Parker".to_string()
+            },
+            GameEvent::PlayerJoined {
+                player_id: player_2_id.clone(),
+                name: "Trevor".to_string()
+            },
+            GameEvent::StateChange(GameState::Startup),
+            GameEvent::PlayersSeated {
+                player_ids: vec![player_1_id.clone(), player_2_id]
+            },
+            GameEvent::CardsDealt {
+                cards_per_player: 12,
+                discard_pile_top
+            },
+            GameEvent::StateChange(GameState::Active),
+            GameEvent::TurnAdvanced {
+                player_id: player_1_id
+            },
+        ]
+    );
+}
+
+#[test]
+fn drawing_and_flipping_are_reported_without_revealing_the_drawn_card() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+    let events = record_events(&mut game);
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    let drawn = game
+        .get_player(&player_1_id)
+        .unwrap()
+        .holding()
+        .unwrap()
+        .get_value()
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 1, column: 2 })
+        .unwrap();
+
+    let flipped = game.get_player(&player_1_id).unwrap().spread.view()[1][2].unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            GameEvent::CardDrawn {
+                player_id: player_1_id.clone()
+            },
+            GameEvent::CardFlipped {
+                player_id: player_1_id.clone(),
+                row: 1,
+                column: 2,
+                value: flipped
+            },
+            GameEvent::CardDiscarded {
+                player_id: player_1_id,
+                value: drawn
+            },
+            GameEvent::TurnAdvanced {
+                player_id: player_2_id
+            },
+        ]
+    );
+}
+
+#[test]
+fn taking_from_the_discard_pile_and_swapping_are_reported() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+    rig_card(&mut game, 0, 0, 3, 9);
+    let events = record_events(&mut game);
+
+    let taken = game
+        .context
+        .discard_pile
+        .top()
+        .unwrap()
+        .get_value()
+        .unwrap();
+    game.start_player_turn(&player_1_id, StartAction::TakeFromDiscardPile)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Swap { row: 0, column: 3 })
+        .unwrap();
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        events[0],
+        GameEvent::CardTakenFromDiscard {
+            player_id: player_1_id.clone(),
+            value: taken
+        }
+    );
+    assert_eq!(
+        events[1],
+        GameEvent::CardSwapped {
+            player_id: player_1_id,
+            row: 0,
+            column: 3,
+            placed: taken,
+            discarded: CardValue::Nine
+        }
+    );
+    assert_eq!(
+        game.context.discard_pile.top().unwrap().get_value(),
+        Some(CardValue::Nine)
+    );
+}
+
+#[test]
+fn clearing_a_column_is_reported() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+    for row in 0..3 {
+        rig_card(&mut game, 0, row, 0, 5);
+    }
+    let spread = &mut game.context.players[0].spread;
+    spread.flip_at(0, 0).unwrap();
+    spread.flip_at(1, 0).unwrap();
+    let events = record_events(&mut game);
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 2, column: 0 })
+        .unwrap();
+
+    assert!(events.lock().unwrap().contains(&GameEvent::LineCleared {
+        player_id: player_1_id,
+        line: Line::Column(0),
+        cards: vec![CardValue::Five; 3]
+    }));
+}
+
+#[test]
+fn the_match_rule_decides_which_lines_are_cleared() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    game.start_with_options(GameOptions {
+        first_player_idx: Some(0),
+        rules: Rules {
+            match_rule: MatchRule {
+                lines: MatchLines::ColumnsAndRows,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap();
+    let player_1_id = game.list_players()[0].id();
+    for column in 0..4 {
+        rig_card(&mut game, 0, 1, column, 8);
+    }
+    let spread = &mut game.context.players[0].spread;
+    for column in 0..3 {
+        spread.flip_at(1, column).unwrap();
+    }
+    let events = record_events(&mut game);
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 1, column: 3 })
+        .unwrap();
+
+    assert!(events.lock().unwrap().contains(&GameEvent::LineCleared {
+        player_id: player_1_id,
+        line: Line::Row(1),
+        cards: vec![CardValue::Eight; 4]
+    }));
+    assert_eq!(game.list_players()[0].spread.remaining_cards().count(), 8);
+}
+
+#[test]
+fn the_last_round_and_the_end_of_the_game_are_reported() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+    flip_all_but(&mut game, 0, (2, 3));
+    let events = record_events(&mut game);
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 2, column: 3 })
+        .unwrap();
+    game.start_player_turn(&player_2_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_2_id, EndAction::Flip { row: 0, column: 0 })
+        .unwrap();
+
+    let events = events.lock().unwrap();
+    let last_round_idx = events
+        .iter()
+        .position(|e| {
+            *e == GameEvent::LastRoundTriggered {
+                player_id: player_1_id.clone(),
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        events[last_round_idx + 1],
+        GameEvent::StateChange(GameState::LastRound)
+    );
+    assert_eq!(
+        events.last(),
+        Some(&GameEvent::GameEnded {
+            result: game.result().unwrap().clone()
+        })
+    );
+}
+
+#[test]
+fn every_subscriber_gets_every_event_until_it_unsubscribes() {
+    let mut game = StratoGame::new();
+    let first = record_events(&mut game);
+    let second = record_events(&mut game);
+    let second_id = game.subscribe(|_| {});
+
+    game.add_player("Parker").unwrap();
+    assert!(game.unsubscribe(second_id));
+    assert!(!game.unsubscribe(second_id));
+    game.add_player("Trevor").unwrap();
+
+    assert_eq!(first.lock().unwrap().len(), 2);
+    assert_eq!(*first.lock().unwrap(), *second.lock().unwrap());
+
+    game.unsubscribe_all();
+    game.start().unwrap();
+    assert_eq!(first.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn a_game_can_be_shared_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<StratoGame<'static>>();
+
+    let game = Arc::new(Mutex::new(StratoGame::new()));
+    let (_, receiver) = game.lock().unwrap().subscribe_channel();
+
+    let handle = std::thread::spawn({
+        let game = game.clone();
+        move || {
+            let mut game = game.lock().unwrap();
+            seat_and_start(&mut game, ["Parker", "Trevor"], GameOptions::default())
+        }
+    });
+    let [player_1_id, _] = handle.join().unwrap();
+
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(events.len(), 7);
+    assert_eq!(
+        events.last(),
+        Some(&GameEvent::TurnAdvanced {
+            player_id: player_1_id
+        })
+    );
+}
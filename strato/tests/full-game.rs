@@ -1,12 +1,13 @@
 use strato::{
     self,
-    game::{GameOptions, GameState, StratoGame},
+    game::{GameState, StratoGame},
     player::{EndAction, StartAction},
 };
 
-// #[test]
-fn full_game_1() {
-    let mut game = StratoGame::new();
+const SEED: u64 = 20220719;
+
+fn play_full_game(seed: u64) -> StratoGame<'static> {
+    let mut game = StratoGame::with_seed(seed);
     let jackie_id = game.add_player("Jackie").unwrap();
     let bryan_id = game.add_player("Bryan").unwrap();
     game.start().unwrap();
@@ -17,41 +18,89 @@ fn full_game_1() {
 
     assert_eq!(game.state, GameState::DetermineFirstPlayer);
 
-    // TODO: figure out who goes first
+    for player_id in [&jackie_id, &bryan_id] {
+        game.player_flip_to_determine_who_is_first(player_id, 1, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 1, 1)
+            .unwrap();
+    }
+    for column in [3, 2, 1] {
+        for player_idx in game.tied_for_first().to_vec() {
+            let player_id = game.context.players[player_idx].id();
+            game.player_flip_to_determine_who_is_first(&player_id, 0, column)
+                .unwrap();
+        }
+    }
 
     assert_eq!(game.state, GameState::Active);
 
+    let first_player_idx = game.context.current_player_idx.unwrap();
+    let (first_id, second_id) = if first_player_idx == 0 {
+        (jackie_id, bryan_id)
+    } else {
+        (bryan_id, jackie_id)
+    };
+
     /*
      * Round #1
      */
 
-    game.start_player_turn(&jackie_id, StartAction::DrawFromDeck)
-        .expect("Couldn't start jackie's turn");
-    game.end_player_turn(&jackie_id, EndAction::Flip { row: 1, column: 2 })
-        .expect("Couldn't end jackie's turn");
+    game.start_player_turn(&first_id, StartAction::DrawFromDeck)
+        .expect("Couldn't start first player's turn");
+    game.end_player_turn(&first_id, EndAction::Flip { row: 1, column: 2 })
+        .expect("Couldn't end first player's turn");
     assert_eq!(game.context.discard_pile.size(), 2);
 
-    game.start_player_turn(&bryan_id, StartAction::TakeFromDiscardPile)
-        .expect("Couldn't start bryan's turn");
-    game.end_player_turn(&bryan_id, EndAction::Swap { row: 2, column: 2 })
-        .expect("Couldn't end bryan's turn");
+    game.start_player_turn(&second_id, StartAction::TakeFromDiscardPile)
+        .expect("Couldn't start second player's turn");
+    game.end_player_turn(&second_id, EndAction::Swap { row: 2, column: 2 })
+        .expect("Couldn't end second player's turn");
     assert_eq!(game.context.discard_pile.size(), 2); // hasn't changed because this was taken from discard pile
 
     /*
      * Round #2
      */
 
-    game.start_player_turn(&jackie_id, StartAction::DrawFromDeck)
-        .expect("Couldn't start jackie's 2nd turn");
-    game.end_player_turn(&jackie_id, EndAction::Swap { row: 2, column: 3 })
-        .expect("Couldn't end jackie's 2nd turn");
+    game.start_player_turn(&first_id, StartAction::DrawFromDeck)
+        .expect("Couldn't start first player's 2nd turn");
+    game.end_player_turn(&first_id, EndAction::Swap { row: 2, column: 3 })
+        .expect("Couldn't end first player's 2nd turn");
     assert_eq!(game.context.discard_pile.size(), 3);
 
-    game.start_player_turn(&bryan_id, StartAction::DrawFromDeck)
-        .expect("Couldn't start bryan's 2nd turn");
-    game.end_player_turn(&bryan_id, EndAction::Flip { row: 0, column: 0 })
-        .expect("Couldn't end bryan's 2nd turn");
+    game.start_player_turn(&second_id, StartAction::DrawFromDeck)
+        .expect("Couldn't start second player's 2nd turn");
+    game.end_player_turn(&second_id, EndAction::Flip { row: 0, column: 0 })
+        .expect("Couldn't end second player's 2nd turn");
     assert_eq!(game.context.discard_pile.size(), 4);
 
-    // TODO: test how many are flipped over by now
+    let first = game.get_player(&first_id).unwrap();
+    let second = game.get_player(&second_id).unwrap();
+    assert_eq!(first.spread.flipped_cards(), 4);
+    assert_eq!(second.spread.flipped_cards(), 4);
+
+    game
+}
+
+#[test]
+fn full_game_1() {
+    play_full_game(SEED);
+}
+
+#[test]
+fn the_same_seed_plays_the_same_game() {
+    let game = play_full_game(SEED);
+    let replayed = play_full_game(SEED);
+
+    assert_eq!(game.seed(), SEED);
+    assert_eq!(game.state, replayed.state);
+    assert_eq!(game.context, replayed.context);
+}
+
+#[test]
+fn a_different_seed_deals_a_different_game() {
+    let game = play_full_game(SEED);
+    let other = play_full_game(SEED + 1);
+
+    assert_ne!(game.context.deck, other.context.deck);
+    assert_ne!(game.list_players()[0].id(), other.list_players()[0].id());
 }
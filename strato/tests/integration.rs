@@ -1,25 +1,22 @@
+mod common;
+
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     Arc,
 };
 
 use strato::{
     self,
-    card::Deck,
-    game::{GameEvent, GameOptions, GameStartupError, GameState, PlayerTurnError, StratoGame},
+    card::{Deck, SpreadSize},
+    game::{
+        FirstPlayerTieBreak, GameEvent, GameOptions, GameStartupError, GameState, PlayerTurnError,
+        StratoGame,
+    },
     player::{EndAction, StartAction},
+    rules::Rules,
 };
 
-fn start_game_with_order() -> (StratoGame<'static>, String, String) {
-    let mut game = StratoGame::new();
-    let player_1_id = game.add_player("Parker").unwrap();
-    let player_2_id = game.add_player("Trevor").unwrap();
-    game.start_with_options(GameOptions {
-        first_player_idx: Some(0),
-    })
-    .unwrap();
-    (game, player_1_id, player_2_id)
-}
+use common::{rig_card, start_two_player_game};
 
 #[test]
 fn a_game_can_be_initialized() {
@@ -54,11 +51,25 @@ fn a_game_can_be_started_with_specific_start_player() {
     game.add_player("Trevor").unwrap();
     let result = game.start_with_options(GameOptions {
         first_player_idx: Some(previous_winner_idx),
+        ..Default::default()
     });
     assert!(result.is_ok());
     assert_eq!(game.state, GameState::Active);
 }
 
+#[test]
+fn the_start_player_must_be_at_the_table() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    let result = game.start_with_options(GameOptions {
+        first_player_idx: Some(5),
+        ..Default::default()
+    });
+    assert_eq!(result, Err(GameStartupError::FirstPlayerDoesntExist));
+    assert_eq!(game.state, GameState::WaitingForPlayers);
+}
+
 #[test]
 fn cant_start_without_players() {
     let mut game = StratoGame::new();
@@ -69,7 +80,7 @@ fn cant_start_without_players() {
 
 #[test]
 fn a_started_game_deals_cards_to_players() {
-    let (game, player_1_id, _) = start_game_with_order();
+    let (game, player_1_id, _) = start_two_player_game(GameOptions::default());
     let player = game.get_player(&player_1_id).unwrap();
 
     assert_eq!(
@@ -88,7 +99,7 @@ fn a_started_game_deals_cards_to_players() {
 
 #[test]
 fn starting_multiple_times_is_inconsequential() {
-    let (mut game, _, _) = start_game_with_order();
+    let (mut game, _, _) = start_two_player_game(GameOptions::default());
     let deck_snapshot = game.context.deck.clone();
     assert!(game.start().is_err());
     assert_eq!(
@@ -109,6 +120,156 @@ fn can_list_players() {
     assert!(game.list_players().iter().eq(vec![player_1, player_2]));
 }
 
+#[test]
+fn player_names_are_owned_and_trimmed() {
+    let mut game = StratoGame::new();
+    let name = format!("  {}  ", "Parker");
+    let player_id = game.add_player(name).unwrap();
+    assert_eq!(game.get_player(player_id).unwrap().name(), "Parker");
+}
+
+#[test]
+fn player_names_must_be_a_reasonable_length() {
+    let mut game = StratoGame::new();
+    assert_eq!(
+        game.add_player("   "),
+        Err(GameStartupError::InvalidPlayerName)
+    );
+    assert_eq!(
+        game.add_player("A".repeat(21)),
+        Err(GameStartupError::InvalidPlayerName)
+    );
+    assert!(game.add_player("A".repeat(20)).is_ok());
+}
+
+#[test]
+fn player_names_must_be_unique() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    assert_eq!(
+        game.add_player("parker "),
+        Err(GameStartupError::DuplicatePlayerName("parker".to_string()))
+    );
+    assert_eq!(game.list_players().len(), 1);
+}
+
+fn add_players(game: &mut StratoGame, count: usize) {
+    for n in 0..count {
+        game.add_player(format!("Player {}", n + 1)).unwrap();
+    }
+}
+
+#[test]
+fn spreads_can_be_dealt_in_other_sizes() {
+    for size in [SpreadSize::new(3, 3), SpreadSize::new(4, 4)] {
+        let mut game = StratoGame::new();
+        add_players(&mut game, 2);
+        game.start_with_options(GameOptions {
+            rules: Rules {
+                spread_size: size,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .unwrap();
+
+        for player in game.list_players() {
+            assert_eq!(player.spread.size(), size);
+            assert_eq!(player.spread.remaining_cards().count(), size.cards());
+        }
+        let cards_used = 2 * size.cards() + 1;
+        assert_eq!(game.context.deck.size(), Deck::FULL_SIZE - cards_used);
+    }
+}
+
+#[test]
+fn spreads_need_at_least_two_rows() {
+    let mut game = StratoGame::new();
+    add_players(&mut game, 2);
+    let result = game.start_with_options(GameOptions {
+        rules: Rules {
+            spread_size: SpreadSize::new(1, 4),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    assert_eq!(result, Err(GameStartupError::InvalidSpreadSize));
+}
+
+#[test]
+fn a_game_holds_eight_players_by_default() {
+    let mut game = StratoGame::new();
+    add_players(&mut game, StratoGame::DEFAULT_MAX_PLAYERS);
+    assert_eq!(
+        game.add_player("Parker"),
+        Err(GameStartupError::TooManyPlayers)
+    );
+    assert_eq!(game.list_players().len(), 8);
+}
+
+#[test]
+fn big_tables_play_with_more_decks() {
+    let mut game = StratoGame::new();
+    game.set_max_players(12).unwrap();
+    add_players(&mut game, 12);
+    game.start().unwrap();
+
+    let cards_used = 12 * 12 + 1;
+    assert_eq!(game.context.deck.size(), 2 * Deck::FULL_SIZE - cards_used);
+}
+
+#[test]
+fn big_spreads_play_with_more_decks() {
+    let mut game = StratoGame::new();
+    add_players(&mut game, 5);
+    game.start_with_options(GameOptions {
+        rules: Rules {
+            spread_size: SpreadSize::new(5, 6),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap();
+
+    let cards_used = 5 * 30 + 1;
+    assert_eq!(game.context.deck.size(), 2 * Deck::FULL_SIZE - cards_used);
+}
+
+#[test]
+fn the_deck_must_be_big_enough_for_the_table() {
+    let mut game = StratoGame::new();
+    game.set_max_players(13).unwrap();
+    add_players(&mut game, 13);
+
+    let result = game.start_with_options(GameOptions {
+        deck_sets: Some(1),
+        ..Default::default()
+    });
+    assert_eq!(result, Err(GameStartupError::DeckTooSmall));
+    assert_eq!(game.state, GameState::WaitingForPlayers);
+}
+
+#[test]
+fn the_max_players_can_only_change_in_the_lobby() {
+    let mut game = StratoGame::new();
+    add_players(&mut game, 3);
+    assert_eq!(
+        game.set_max_players(2),
+        Err(GameStartupError::TooManyPlayers)
+    );
+    assert_eq!(
+        game.set_max_players(1),
+        Err(GameStartupError::NotEnoughPlayers)
+    );
+
+    game.start().unwrap();
+    assert_eq!(
+        game.set_max_players(4),
+        Err(GameStartupError::PlayersListLocked)
+    );
+    assert_eq!(game.max_players(), StratoGame::DEFAULT_MAX_PLAYERS);
+}
+
 #[test]
 fn cant_change_players_after_game_starts() {
     let mut game = StratoGame::new();
@@ -116,6 +277,7 @@ fn cant_change_players_after_game_starts() {
     game.add_player("Lexi").unwrap();
     game.start_with_options(GameOptions {
         first_player_idx: Some(0),
+        ..Default::default()
     })
     .unwrap();
     assert_eq!(game.state, GameState::Active);
@@ -127,14 +289,14 @@ fn cant_change_players_after_game_starts() {
 
 #[test]
 fn the_first_turn_can_take_from_discard_pile() {
-    let (mut game, player_1_id, _) = start_game_with_order();
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
     let turn = game.start_player_turn(&player_1_id, StartAction::TakeFromDiscardPile);
     assert!(turn.is_ok());
 }
 
 #[test]
 fn a_player_can_draw_and_flip() {
-    let (mut game, player_1_id, _) = start_game_with_order();
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
     game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
         .expect("Couldn't start turn");
     assert!(game.get_player(&player_1_id).unwrap().holding().is_some());
@@ -146,7 +308,7 @@ fn a_player_can_draw_and_flip() {
 
 #[test]
 fn a_player_can_take_and_swap() {
-    let (mut game, player_1_id, _) = start_game_with_order();
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
     game.start_player_turn(&player_1_id, StartAction::TakeFromDiscardPile)
         .expect("Couldn't start turn");
     assert!(game.get_player(&player_1_id).unwrap().holding().is_some());
@@ -158,7 +320,7 @@ fn a_player_can_take_and_swap() {
 
 #[test]
 fn cant_flip_same_card_twice() {
-    let (mut game, player_1_id, player_2_id) = start_game_with_order();
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
 
     const ROW: usize = 0;
     const COLUMN: usize = 1;
@@ -202,7 +364,7 @@ fn cant_flip_same_card_twice() {
 
 #[test]
 fn cant_start_turn_twice() {
-    let (mut game, player_1_id, _) = start_game_with_order();
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
 
     game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
         .expect("Couldn't start Player 1's turn");
@@ -218,6 +380,7 @@ fn multiple_players_session_1() {
     let james_id = game.add_player("James").unwrap();
     game.start_with_options(GameOptions {
         first_player_idx: Some(0),
+        ..Default::default()
     })
     .unwrap();
 
@@ -274,11 +437,13 @@ fn can_subscribe_to_state_changes() {
         move |e| {
             callback_triggered.store(true, Ordering::Relaxed);
 
-            assert!(
-                e == GameEvent::StateChange(&GameState::Startup)
-                    || e == GameEvent::StateChange(&GameState::DetermineFirstPlayer)
-                    || e == GameEvent::StateChange(&GameState::Active)
-            );
+            if let GameEvent::StateChange(state) = e {
+                assert!(
+                    state == GameState::Startup
+                        || state == GameState::DetermineFirstPlayer
+                        || state == GameState::Active
+                );
+            }
         }
     });
 
@@ -286,13 +451,14 @@ fn can_subscribe_to_state_changes() {
     let _ = game.add_player("Trevor").unwrap();
     game.start_with_options(GameOptions {
         first_player_idx: Some(0),
+        ..Default::default()
     })
     .unwrap();
 
     game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
         .expect("Couldn't start turn");
 
-    assert_eq!(callback_triggered.load(Ordering::Relaxed), true);
+    assert!(callback_triggered.load(Ordering::Relaxed));
 }
 
 #[test]
@@ -300,7 +466,11 @@ fn can_flip_to_determine_who_is_first() {
     let mut game = StratoGame::new();
     let cassie_id = game.add_player("Cassie").unwrap();
     let james_id = game.add_player("James").unwrap();
-    game.start().unwrap();
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Random,
+        ..Default::default()
+    })
+    .unwrap();
 
     assert_eq!(game.state, GameState::DetermineFirstPlayer);
 
@@ -345,3 +515,212 @@ fn cant_flip_too_many_cards_to_determine_first_player() {
     let result = game.player_flip_to_determine_who_is_first(&cassie_id, 2, 0);
     assert_eq!(result.unwrap_err(), PlayerTurnError::TooManyCardsFlipped);
 }
+
+/// Cassie and James tie on 24 with Lexi far behind. Cassie has the higher tie-break card.
+fn start_game_with_tie_for_first(
+    first_player_tie_break: FirstPlayerTieBreak,
+) -> (StratoGame<'static>, String, String, String) {
+    let mut game = StratoGame::new();
+    let cassie_id = game.add_player("Cassie").unwrap();
+    let james_id = game.add_player("James").unwrap();
+    let lexi_id = game.add_player("Lexi").unwrap();
+    game.start_with_options(GameOptions {
+        first_player_tie_break,
+        ..Default::default()
+    })
+    .unwrap();
+
+    for (player_idx, value, tie_break_value) in [(0, 12, 5), (1, 12, 3), (2, -2, 0)] {
+        rig_card(&mut game, player_idx, 0, 0, value);
+        rig_card(&mut game, player_idx, 0, 1, value);
+        rig_card(&mut game, player_idx, 1, 0, tie_break_value);
+    }
+
+    for player_id in [&cassie_id, &james_id, &lexi_id] {
+        game.player_flip_to_determine_who_is_first(player_id, 0, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 0, 1)
+            .unwrap();
+    }
+
+    (game, cassie_id, james_id, lexi_id)
+}
+
+#[test]
+fn tied_players_flip_another_card_to_determine_who_is_first() {
+    let (mut game, cassie_id, james_id, lexi_id) =
+        start_game_with_tie_for_first(FirstPlayerTieBreak::FlipAnotherCard);
+
+    assert_eq!(game.state, GameState::DetermineFirstPlayer);
+    assert_eq!(game.tied_for_first(), &[0, 1]);
+
+    let result = game.player_flip_to_determine_who_is_first(&lexi_id, 1, 0);
+    assert_eq!(result.unwrap_err(), PlayerTurnError::NotInTieBreak);
+
+    game.player_flip_to_determine_who_is_first(&cassie_id, 1, 0)
+        .unwrap();
+    let result = game.player_flip_to_determine_who_is_first(&cassie_id, 1, 1);
+    assert_eq!(result.unwrap_err(), PlayerTurnError::TooManyCardsFlipped);
+    assert_eq!(game.state, GameState::DetermineFirstPlayer);
+
+    game.player_flip_to_determine_who_is_first(&james_id, 1, 0)
+        .unwrap();
+
+    assert_eq!(game.state, GameState::Active);
+    assert_eq!(game.context.current_player_idx, Some(0));
+    assert!(game.tied_for_first().is_empty());
+}
+
+#[test]
+fn tie_for_first_can_go_to_the_youngest_player() {
+    let (game, _, _, _) =
+        start_game_with_tie_for_first(FirstPlayerTieBreak::Youngest(vec![2, 1, 0]));
+
+    assert_eq!(game.state, GameState::Active);
+    assert_eq!(game.context.current_player_idx, Some(1));
+}
+
+#[test]
+fn the_youngest_to_oldest_order_must_name_players_at_the_table() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    let result = game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Youngest(vec![1, 0, 7]),
+        ..Default::default()
+    });
+    assert_eq!(result, Err(GameStartupError::InvalidTieBreakOrder));
+    assert_eq!(game.state, GameState::WaitingForPlayers);
+
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Youngest(vec![1, 0]),
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(StratoGame::load(game.save()).is_ok());
+}
+
+#[test]
+fn tie_for_first_can_be_picked_at_random() {
+    let (game, _, _, _) = start_game_with_tie_for_first(FirstPlayerTieBreak::Random);
+
+    assert_eq!(game.state, GameState::Active);
+    assert!([Some(0), Some(1)].contains(&game.context.current_player_idx));
+}
+
+#[test]
+fn discard_pile_is_reshuffled_into_an_empty_deck() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    // Build up the discard pile a little
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 0 })
+        .unwrap();
+    game.start_player_turn(&player_2_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_2_id, EndAction::Flip { row: 0, column: 0 })
+        .unwrap();
+    assert_eq!(game.context.discard_pile.size(), 3);
+
+    while game.context.deck.draw().is_some() {}
+
+    let reshuffled_cards = Arc::new(AtomicUsize::new(0));
+    game.subscribe({
+        let reshuffled_cards = reshuffled_cards.clone();
+
+        move |e| {
+            if let GameEvent::DeckReshuffled { cards } = e {
+                reshuffled_cards.store(cards, Ordering::Relaxed);
+            }
+        }
+    });
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .expect("Couldn't draw after the deck ran out");
+
+    assert_eq!(reshuffled_cards.load(Ordering::Relaxed), 2);
+    assert_eq!(game.context.discard_pile.size(), 1);
+    assert_eq!(game.context.deck.size(), 1);
+    assert!(game
+        .get_player(&player_1_id)
+        .unwrap()
+        .holding()
+        .unwrap()
+        .is_flipped());
+}
+
+#[test]
+fn cant_draw_when_deck_and_discard_pile_are_exhausted() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+    while game.context.deck.draw().is_some() {}
+    assert_eq!(game.context.discard_pile.size(), 1);
+
+    let result = game.start_player_turn(&player_1_id, StartAction::DrawFromDeck);
+    assert_eq!(result.unwrap_err(), PlayerTurnError::DeckEmpty);
+    assert_eq!(game.context.discard_pile.size(), 1);
+}
+
+#[test]
+fn a_player_remembers_where_their_card_came_from() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    assert_eq!(
+        game.get_player(&player_1_id).unwrap().held_from(),
+        Some(StartAction::DrawFromDeck)
+    );
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 0 })
+        .unwrap();
+    assert_eq!(game.get_player(&player_1_id).unwrap().held_from(), None);
+
+    game.start_player_turn(&player_2_id, StartAction::TakeFromDiscardPile)
+        .unwrap();
+    assert_eq!(
+        game.get_player(&player_2_id).unwrap().held_from(),
+        Some(StartAction::TakeFromDiscardPile)
+    );
+}
+
+#[test]
+fn cant_flip_after_taking_from_discard_pile() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::TakeFromDiscardPile)
+        .unwrap();
+    let result = game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 0 });
+    assert_eq!(
+        result.unwrap_err(),
+        PlayerTurnError::CantFlipWithCardFromDiscardPile
+    );
+
+    // Still holding the card, so the turn can end with a swap instead
+    assert!(game.get_player(&player_1_id).unwrap().holding().is_some());
+    game.end_player_turn(&player_1_id, EndAction::Swap { row: 0, column: 0 })
+        .unwrap();
+}
+
+#[test]
+fn a_seed_in_the_options_makes_the_deal_repeatable() {
+    let deal = || {
+        let mut game = StratoGame::new();
+        game.add_player("Parker").unwrap();
+        game.add_player("Trevor").unwrap();
+        game.start_with_options(GameOptions {
+            first_player_idx: Some(0),
+            seed: Some(42),
+            ..Default::default()
+        })
+        .unwrap();
+        game
+    };
+
+    let game = deal();
+    let again = deal();
+    assert_eq!(game.context.deck, again.context.deck);
+    assert_eq!(
+        game.list_players()[0].spread,
+        again.list_players()[0].spread
+    );
+}
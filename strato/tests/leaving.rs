@@ -0,0 +1,240 @@
+mod common;
+
+use strato::{
+    self,
+    game::{
+        FirstPlayerTieBreak, GameOptions, GameStartupError, GameState, PlayerTurnError, StratoGame,
+    },
+    player::{EndAction, StartAction},
+};
+
+use common::{flip_all_but, rig_card, start_game};
+
+fn start_game_with_three() -> (StratoGame<'static>, [String; 3]) {
+    start_game(["Parker", "Trevor", "Lexi"], GameOptions::default())
+}
+
+fn play_turn(game: &mut StratoGame, player_id: &str, row: usize, column: usize) {
+    game.start_player_turn(player_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(player_id, EndAction::Flip { row, column })
+        .unwrap();
+}
+
+#[test]
+fn players_can_leave_the_lobby() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+
+    game.remove_player(&player_1_id).unwrap();
+    assert_eq!(game.list_players().len(), 1);
+    assert_eq!(
+        game.remove_player(&player_1_id),
+        Err(GameStartupError::PlayerDoesntExist)
+    );
+
+    // The name is free again.
+    game.add_player("Parker").unwrap();
+    game.start().unwrap();
+    let player_id = game.list_players()[0].id();
+    assert_eq!(
+        game.remove_player(player_id),
+        Err(GameStartupError::PlayersListLocked)
+    );
+}
+
+#[test]
+fn forfeiting_needs_a_game_in_progress() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Parker").unwrap();
+    assert_eq!(
+        game.forfeit(&player_1_id),
+        Err(PlayerTurnError::GameNotStarted)
+    );
+
+    let (mut game, _) = start_game_with_three();
+    assert_eq!(
+        game.forfeit("nobody"),
+        Err(PlayerTurnError::PlayerDoesntExist)
+    );
+}
+
+#[test]
+fn the_turn_passes_on_when_the_current_player_forfeits() {
+    let (mut game, player_ids) = start_game_with_three();
+    let discard_pile_size = game.context.discard_pile.size();
+
+    game.start_player_turn(&player_ids[0], StartAction::DrawFromDeck)
+        .unwrap();
+    game.forfeit(&player_ids[0]).unwrap();
+
+    assert_eq!(game.list_players().len(), 2);
+    assert_eq!(game.context.discard_pile.size(), discard_pile_size + 1);
+    assert_eq!(game.context.current_player_idx, Some(0));
+    assert_eq!(game.list_players()[0].id(), player_ids[1]);
+
+    play_turn(&mut game, &player_ids[1], 0, 0);
+    play_turn(&mut game, &player_ids[2], 0, 0);
+    play_turn(&mut game, &player_ids[1], 0, 1);
+}
+
+#[test]
+fn the_current_player_keeps_their_turn_when_someone_before_them_forfeits() {
+    let (mut game, player_ids) = start_game_with_three();
+    play_turn(&mut game, &player_ids[0], 0, 0);
+    play_turn(&mut game, &player_ids[1], 0, 0);
+
+    game.forfeit(&player_ids[0]).unwrap();
+    assert_eq!(game.context.current_player_idx, Some(1));
+
+    play_turn(&mut game, &player_ids[2], 0, 0);
+    play_turn(&mut game, &player_ids[1], 0, 1);
+}
+
+#[test]
+fn the_last_player_standing_ends_the_game() {
+    let (mut game, player_ids) = start_game_with_three();
+
+    game.forfeit(&player_ids[1]).unwrap();
+    assert_eq!(game.state, GameState::Active);
+    game.forfeit(&player_ids[0]).unwrap();
+
+    assert_eq!(game.state, GameState::Ended);
+    let result = game.result().unwrap();
+    assert_eq!(result.standings.len(), 1);
+    assert_eq!(result.winners()[0].player_id, player_ids[2]);
+}
+
+#[test]
+fn the_player_before_a_departed_last_player_takes_the_last_turn() {
+    let (mut game, player_ids) = start_game_with_three();
+    flip_all_but(&mut game, 0, (2, 3));
+
+    play_turn(&mut game, &player_ids[0], 2, 3);
+    assert_eq!(game.state, GameState::LastRound);
+
+    game.forfeit(&player_ids[2]).unwrap();
+    play_turn(&mut game, &player_ids[1], 0, 0);
+    assert_eq!(game.state, GameState::Ended);
+}
+
+#[test]
+fn the_game_ends_if_the_last_player_forfeits_on_their_last_turn() {
+    let (mut game, player_ids) = start_game_with_three();
+    flip_all_but(&mut game, 0, (2, 3));
+
+    play_turn(&mut game, &player_ids[0], 2, 3);
+    play_turn(&mut game, &player_ids[1], 0, 0);
+    assert_eq!(game.state, GameState::LastRound);
+
+    game.forfeit(&player_ids[2]).unwrap();
+    assert_eq!(game.state, GameState::Ended);
+}
+
+#[test]
+fn the_last_round_continues_if_the_finisher_forfeits() {
+    let (mut game, player_ids) = start_game_with_three();
+    flip_all_but(&mut game, 0, (2, 3));
+
+    play_turn(&mut game, &player_ids[0], 2, 3);
+    game.forfeit(&player_ids[0]).unwrap();
+    assert_eq!(game.state, GameState::LastRound);
+    assert_eq!(game.finisher_idx(), None);
+
+    play_turn(&mut game, &player_ids[1], 0, 0);
+    assert_eq!(game.state, GameState::LastRound);
+    play_turn(&mut game, &player_ids[2], 0, 0);
+    assert_eq!(game.state, GameState::Ended);
+    assert!(game.scores().unwrap().iter().all(|s| s.penalty == 0));
+}
+
+#[test]
+fn the_first_player_is_determined_without_a_departed_player() {
+    let mut game = StratoGame::new();
+    let player_ids = ["Parker", "Trevor", "Lexi"]
+        .into_iter()
+        .map(|name| game.add_player(name).unwrap())
+        .collect::<Vec<_>>();
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Random,
+        ..Default::default()
+    })
+    .unwrap();
+
+    for player_id in &player_ids[0..2] {
+        game.player_flip_to_determine_who_is_first(player_id, 0, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 0, 1)
+            .unwrap();
+    }
+    assert_eq!(game.state, GameState::DetermineFirstPlayer);
+
+    game.forfeit(&player_ids[2]).unwrap();
+    assert_eq!(game.state, GameState::Active);
+    assert!(game.context.current_player_idx.unwrap() < 2);
+}
+
+/// Parker and Trevor tie on 24, ahead of Lexi on 10 and Cassie on 2, so a tie-break begins.
+fn start_game_with_tie_for_first() -> (StratoGame<'static>, Vec<String>) {
+    let mut game = StratoGame::new();
+    let player_ids = ["Parker", "Trevor", "Lexi", "Cassie"]
+        .into_iter()
+        .map(|name| game.add_player(name).unwrap())
+        .collect::<Vec<_>>();
+    game.start().unwrap();
+
+    for (player_idx, value) in [(0, 12), (1, 12), (2, 5), (3, 1)] {
+        rig_card(&mut game, player_idx, 0, 0, value);
+        rig_card(&mut game, player_idx, 0, 1, value);
+    }
+    for player_id in &player_ids {
+        game.player_flip_to_determine_who_is_first(player_id, 0, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 0, 1)
+            .unwrap();
+    }
+    assert_eq!(game.tied_for_first(), &[0, 1]);
+
+    (game, player_ids)
+}
+
+#[test]
+fn a_tie_break_is_called_off_when_everyone_in_it_forfeits() {
+    let (mut game, player_ids) = start_game_with_tie_for_first();
+
+    game.forfeit(&player_ids[0]).unwrap();
+    game.forfeit(&player_ids[1]).unwrap();
+
+    assert_eq!(game.state, GameState::Active);
+    assert!(game.tied_for_first().is_empty());
+    assert_eq!(
+        game.list_players()[game.context.current_player_idx.unwrap()].id(),
+        player_ids[2]
+    );
+}
+
+#[test]
+fn the_last_player_left_in_a_tie_break_goes_first() {
+    let (mut game, player_ids) = start_game_with_tie_for_first();
+
+    game.forfeit(&player_ids[0]).unwrap();
+
+    assert_eq!(game.state, GameState::Active);
+    assert!(game.tied_for_first().is_empty());
+    assert_eq!(
+        game.list_players()[game.context.current_player_idx.unwrap()].id(),
+        player_ids[1]
+    );
+}
+
+#[test]
+fn forfeits_are_replayed() {
+    let (mut game, player_ids) = start_game_with_three();
+    play_turn(&mut game, &player_ids[0], 0, 0);
+    game.forfeit(&player_ids[1]).unwrap();
+    play_turn(&mut game, &player_ids[2], 0, 0);
+
+    let replayed = StratoGame::replay(game.seed(), game.actions()).unwrap();
+    assert_eq!(replayed.context, game.context);
+}
@@ -0,0 +1,266 @@
+use strato::{
+    self,
+    game::{GameState, StratoGame},
+    match_play::{Match, MatchError},
+    player::{EndAction, StartAction},
+    rules::Rules,
+};
+
+fn start_match(threshold: i32) -> (Match<'static>, String, String) {
+    let mut session = Match::with_threshold(threshold);
+    let player_1_id = session.add_player("Parker").unwrap();
+    let player_2_id = session.add_player("Trevor").unwrap();
+    session.start().unwrap();
+
+    let game = session.game_mut();
+    for player_id in [&player_1_id, &player_2_id] {
+        game.player_flip_to_determine_who_is_first(player_id, 1, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 1, 1)
+            .unwrap();
+    }
+
+    // Settle any tie for going first, staying clear of the spots `play_out_hand` uses.
+    let tie_break_spots = [(0, 0), (0, 1), (0, 2), (0, 3), (2, 1), (2, 2)];
+    for (row, column) in tie_break_spots {
+        if game.state != GameState::DetermineFirstPlayer {
+            break;
+        }
+        for player_idx in game.tied_for_first().to_vec() {
+            let player_id = game.context.players[player_idx].id();
+            game.player_flip_to_determine_who_is_first(&player_id, row, column)
+                .unwrap();
+        }
+    }
+    assert_eq!(game.state, GameState::Active);
+
+    (session, player_1_id, player_2_id)
+}
+
+/// The current player flips everything but one card, then goes out; everyone else gets one last turn.
+fn play_out_hand(session: &mut Match) -> usize {
+    let game = session.game_mut();
+    let finisher_idx = game.context.current_player_idx.unwrap();
+
+    let finisher = &mut game.context.players[finisher_idx];
+    for row in 0..3 {
+        for column in 0..4 {
+            if (row, column) != (2, 3) {
+                let _ = finisher.spread.flip_at(row, column);
+            }
+        }
+    }
+
+    let finisher_id = finisher.id();
+    game.start_player_turn(&finisher_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&finisher_id, EndAction::Flip { row: 2, column: 3 })
+        .unwrap();
+    assert_eq!(game.state, GameState::LastRound);
+
+    while game.state == GameState::LastRound {
+        let player_id = game.context.players[game.context.current_player_idx.unwrap()].id();
+        game.start_player_turn(&player_id, StartAction::DrawFromDeck)
+            .unwrap();
+        game.end_player_turn(&player_id, EndAction::Flip { row: 2, column: 0 })
+            .unwrap();
+    }
+    assert_eq!(game.state, GameState::Ended);
+
+    finisher_idx
+}
+
+#[test]
+fn a_finished_hand_has_a_score_breakdown() {
+    let (mut session, _, _) = start_match(Match::DEFAULT_THRESHOLD);
+    assert!(session.game().scores().is_none());
+
+    let finisher_idx = play_out_hand(&mut session);
+    let scores = session.game().scores().unwrap();
+    assert_eq!(scores.len(), 2);
+
+    for (idx, score) in scores.iter().enumerate() {
+        assert_eq!(score.final_score, score.spread_score + score.penalty);
+        if idx != finisher_idx {
+            assert_eq!(score.penalty, 0);
+        }
+    }
+
+    let finisher = &scores[finisher_idx];
+    let other = &scores[1 - finisher_idx];
+    if finisher.spread_score > 0 && finisher.spread_score >= other.spread_score {
+        assert_eq!(finisher.final_score, finisher.spread_score * 2);
+    } else {
+        assert_eq!(finisher.penalty, 0);
+    }
+}
+
+#[test]
+fn a_finished_hand_ranks_every_player_lowest_first() {
+    let (mut session, _, _) = start_match(Match::DEFAULT_THRESHOLD);
+    assert!(session.game().result().is_none());
+
+    play_out_hand(&mut session);
+    let game = session.game();
+    let result = game.result().unwrap();
+    assert_eq!(result.standings.len(), 2);
+
+    let lowest_score = game
+        .scores()
+        .unwrap()
+        .iter()
+        .map(|s| s.final_score)
+        .min()
+        .unwrap();
+    for winner in result.winners() {
+        assert_eq!(winner.place, 1);
+        assert_eq!(winner.final_score, lowest_score);
+    }
+    assert!(result.standings[0].final_score <= result.standings[1].final_score);
+}
+
+#[test]
+fn a_match_starts_at_the_first_hand() {
+    let (session, player_1_id, _) = start_match(Match::DEFAULT_THRESHOLD);
+    assert_eq!(session.hand_number(), 1);
+    assert_eq!(session.total_for(player_1_id), Some(0));
+    assert!(!session.is_over());
+}
+
+#[test]
+fn cant_finish_a_hand_that_is_still_being_played() {
+    let (mut session, _, _) = start_match(Match::DEFAULT_THRESHOLD);
+    assert_eq!(session.finish_hand().unwrap_err(), MatchError::HandNotOver);
+}
+
+#[test]
+fn finishing_a_hand_adds_to_totals_and_deals_the_next_one() {
+    let (mut session, player_1_id, player_2_id) = start_match(i32::MAX);
+    let finisher_idx = play_out_hand(&mut session);
+
+    let hand_scores = session
+        .game()
+        .scores()
+        .unwrap()
+        .iter()
+        .map(|s| s.final_score)
+        .collect::<Vec<_>>();
+
+    session.finish_hand().unwrap();
+
+    assert!(!session.is_over());
+    assert_eq!(session.hand_number(), 2);
+    assert_eq!(session.total_for(&player_1_id), Some(hand_scores[0]));
+    assert_eq!(session.total_for(&player_2_id), Some(hand_scores[1]));
+
+    let game = session.game();
+    assert_eq!(game.state, GameState::Active);
+    assert_eq!(game.context.current_player_idx, Some(finisher_idx));
+    assert_eq!(game.get_player(&player_1_id).unwrap().spread.score(), 0);
+    assert_eq!(
+        game.get_player(&player_2_id)
+            .unwrap()
+            .spread
+            .flipped_cards(),
+        0
+    );
+}
+
+#[test]
+fn totals_carry_across_hands() {
+    let (mut session, player_1_id, _) = start_match(i32::MAX);
+
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+
+    let scores = session.scores();
+    assert_eq!(scores[0].player_id, player_1_id);
+    assert_eq!(scores[0].hands.len(), 2);
+    assert_eq!(scores[0].total, scores[0].hands.iter().sum::<i32>());
+    assert_eq!(session.hand_number(), 3);
+}
+
+#[test]
+fn the_match_ends_when_the_threshold_is_crossed() {
+    let (mut session, _, _) = start_match(i32::MIN);
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+
+    assert!(session.is_over());
+    assert_eq!(session.hand_number(), 1);
+    assert_eq!(session.game().state, GameState::Ended);
+
+    let lowest_total = session.scores().iter().map(|s| s.total).min().unwrap();
+    let winner_ids = session.winner_ids().unwrap();
+    assert!(!winner_ids.is_empty());
+    for winner_id in winner_ids {
+        assert_eq!(session.total_for(winner_id), Some(lowest_total));
+    }
+
+    assert_eq!(session.finish_hand().unwrap_err(), MatchError::MatchOver);
+}
+
+#[test]
+fn the_match_ends_when_a_forfeit_leaves_one_player() {
+    let (mut session, player_1_id, player_2_id) = start_match(Match::DEFAULT_THRESHOLD);
+    session.game_mut().forfeit(&player_1_id).unwrap();
+    session.finish_hand().unwrap();
+
+    assert!(session.is_over());
+    assert_eq!(session.winner_ids(), Some(&[player_2_id][..]));
+    assert!(session.scores()[0].forfeited);
+    assert!(session.scores()[0].hands.is_empty());
+    assert_eq!(session.finish_hand().unwrap_err(), MatchError::MatchOver);
+}
+
+#[test]
+fn a_player_who_forfeits_is_out_of_the_match() {
+    let mut session = Match::with_rules(Rules {
+        initial_flip: false,
+        score_threshold: i32::MAX,
+        ..Rules::default()
+    });
+    let player_ids = ["Parker", "Trevor", "Lexi"]
+        .into_iter()
+        .map(|name| session.add_player(name).unwrap())
+        .collect::<Vec<_>>();
+    session.start().unwrap();
+
+    session.game_mut().forfeit(&player_ids[2]).unwrap();
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+
+    assert!(!session.is_over());
+    assert_eq!(session.game().list_players().len(), 2);
+    let lexi = &session.scores()[2];
+    assert!(lexi.forfeited);
+    assert!(lexi.hands.is_empty());
+
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+    assert_eq!(session.scores()[0].hands.len(), 2);
+    assert!(session.scores()[2].hands.is_empty());
+}
+
+#[test]
+fn later_hands_can_be_replayed_on_their_own() {
+    let (mut session, _, _) = start_match(i32::MAX);
+    play_out_hand(&mut session);
+    session.finish_hand().unwrap();
+
+    let game = session.game_mut();
+    for _ in 0..4 {
+        let player_id = game.context.players[game.context.current_player_idx.unwrap()].id();
+        game.start_player_turn(&player_id, StartAction::DrawFromDeck)
+            .unwrap();
+        game.end_player_turn(&player_id, EndAction::Swap { row: 0, column: 0 })
+            .unwrap();
+    }
+
+    let hand = session.game();
+    let replayed = StratoGame::replay(hand.seed(), hand.actions()).unwrap();
+    assert_eq!(replayed.state, hand.state);
+    assert_eq!(replayed.context, hand.context);
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use strato::{
+    self,
+    game::{GameOptions, GameState, PlayerTurnError, StratoGame},
+    player::{EndAction, StartAction},
+    replay::{Action, ReplayError},
+};
+
+use common::game_in_progress;
+
+fn play_a_few_turns(seed: u64) -> (StratoGame<'static>, String, String) {
+    let (mut game, player_1_id, player_2_id) = game_in_progress(seed);
+    game.end_player_turn(&player_2_id, EndAction::Swap { row: 0, column: 0 })
+        .unwrap();
+    (game, player_1_id, player_2_id)
+}
+
+#[test]
+fn accepted_commands_are_logged_in_order() {
+    let (game, player_1_id, player_2_id) = play_a_few_turns(11);
+
+    assert_eq!(
+        game.actions(),
+        &[
+            Action::AddPlayer {
+                name: "Parker".to_string()
+            },
+            Action::AddPlayer {
+                name: "Trevor".to_string()
+            },
+            Action::Start {
+                options: GameOptions {
+                    first_player_idx: Some(0),
+                    ..Default::default()
+                }
+            },
+            Action::StartTurn {
+                player_id: player_1_id.clone(),
+                action: StartAction::DrawFromDeck
+            },
+            Action::EndTurn {
+                player_id: player_1_id,
+                action: EndAction::Flip { row: 1, column: 2 }
+            },
+            Action::StartTurn {
+                player_id: player_2_id.clone(),
+                action: StartAction::DrawFromDeck
+            },
+            Action::EndTurn {
+                player_id: player_2_id,
+                action: EndAction::Swap { row: 0, column: 0 }
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejected_commands_are_not_logged() {
+    let (mut game, player_1_id, _) = play_a_few_turns(11);
+    let logged = game.actions().len();
+
+    let result = game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 0 });
+    assert!(result.is_err());
+    assert_eq!(game.actions().len(), logged);
+}
+
+#[test]
+fn a_game_can_be_replayed_exactly() {
+    let (game, _, _) = play_a_few_turns(11);
+
+    let replayed = StratoGame::replay(game.seed(), game.actions()).unwrap();
+    assert_eq!(replayed.state, GameState::Active);
+    assert_eq!(replayed.context, game.context);
+    assert_eq!(replayed.actions(), game.actions());
+}
+
+#[test]
+fn determining_the_first_player_can_be_replayed() {
+    let mut game = StratoGame::new();
+    let player_1_id = game.add_player("Cassie").unwrap();
+    let player_2_id = game.add_player("James").unwrap();
+    game.start().unwrap();
+
+    for player_id in [&player_1_id, &player_2_id] {
+        game.player_flip_to_determine_who_is_first(player_id, 0, 0)
+            .unwrap();
+        game.player_flip_to_determine_who_is_first(player_id, 0, 1)
+            .unwrap();
+    }
+
+    let replayed = StratoGame::replay(game.seed(), game.actions()).unwrap();
+    assert_eq!(replayed.state, game.state);
+    assert_eq!(replayed.context, game.context);
+}
+
+#[test]
+fn replaying_an_invalid_action_reports_where_it_failed() {
+    let (game, player_1_id, _) = play_a_few_turns(11);
+
+    let mut actions = game.actions().to_vec();
+    actions.push(Action::StartTurn {
+        player_id: player_1_id.clone(),
+        action: StartAction::DrawFromDeck,
+    });
+    actions.push(Action::StartTurn {
+        player_id: player_1_id,
+        action: StartAction::DrawFromDeck,
+    });
+
+    let result = StratoGame::replay(game.seed(), &actions);
+    assert_eq!(
+        result.unwrap_err(),
+        ReplayError::PlayerTurnError(8, PlayerTurnError::TurnAlreadyStarted)
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use strato::{
+    self,
+    card::DeckComposition,
+    game::{GameOptions, GameState, PlayerTurnError, StratoGame},
+    match_play::Match,
+    player::{EndAction, StartAction},
+    rules::Rules,
+};
+
+use common::{rig_card, start_two_player_game};
+
+/// Player 1 finishes with a high score while player 2 holds nothing but -2s, then both finish.
+fn play_to_a_losing_finish(game: &mut StratoGame, player_1_id: &str, player_2_id: &str) {
+    for row in 0..3 {
+        for column in 0..4 {
+            rig_card(game, 0, row, column, (5 + row + column) as i32);
+            rig_card(game, 1, row, column, -2);
+            if (row, column) != (2, 3) {
+                game.context.players[0].spread.flip_at(row, column).unwrap();
+            }
+        }
+    }
+
+    for (player_id, row) in [(player_1_id, 2), (player_2_id, 0)] {
+        game.start_player_turn(player_id, StartAction::DrawFromDeck)
+            .unwrap();
+        game.end_player_turn(player_id, EndAction::Flip { row, column: 3 })
+            .unwrap();
+    }
+    assert_eq!(game.state, GameState::Ended);
+}
+
+#[test]
+fn the_presets_differ_only_in_the_deck() {
+    assert_eq!(Rules::default(), Rules::family());
+    assert_eq!(
+        Rules::official().deck_composition,
+        DeckComposition::official()
+    );
+    assert_eq!(
+        Rules {
+            deck_composition: DeckComposition::uniform(),
+            ..Rules::official()
+        },
+        Rules::family()
+    );
+}
+
+#[test]
+fn the_game_plays_by_the_rules_it_started_with() {
+    let (game, _, _) = start_two_player_game(GameOptions {
+        rules: Rules::official(),
+        ..Default::default()
+    });
+    assert_eq!(game.rules(), &Rules::official());
+}
+
+#[test]
+fn the_finisher_penalty_can_be_turned_off() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+    play_to_a_losing_finish(&mut game, &player_1_id, &player_2_id);
+    assert!(game.scores().unwrap()[0].penalty > 0);
+
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions {
+        rules: Rules {
+            finisher_penalty: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    play_to_a_losing_finish(&mut game, &player_1_id, &player_2_id);
+    assert!(game.scores().unwrap().iter().all(|s| s.penalty == 0));
+}
+
+#[test]
+fn the_initial_flip_can_be_skipped() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    game.start_with_options(GameOptions {
+        rules: Rules {
+            initial_flip: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(game.state, GameState::Active);
+    assert!(game.context.current_player_idx.is_some());
+    for player in game.list_players() {
+        assert_eq!(player.spread.flipped_cards(), 0);
+    }
+}
+
+#[test]
+fn discarding_to_flip_can_be_disallowed() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions {
+        rules: Rules {
+            discard_then_flip: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    assert_eq!(
+        game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 0 }),
+        Err(PlayerTurnError::DiscardThenFlipNotAllowed)
+    );
+    game.end_player_turn(&player_1_id, EndAction::Swap { row: 0, column: 0 })
+        .unwrap();
+}
+
+#[test]
+fn a_match_uses_its_rules_for_every_hand() {
+    let rules = Rules {
+        score_threshold: 50,
+        ..Rules::official()
+    };
+    let mut session = Match::with_rules(rules.clone());
+    session.add_player("Parker").unwrap();
+    session.add_player("Trevor").unwrap();
+    session.start().unwrap();
+
+    assert_eq!(session.threshold(), 50);
+    assert_eq!(session.game().rules(), &rules);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use strato::{
+    self,
+    card::{CardValue, Deck, DeckComposition, PlayerSpread, SpreadSize},
+    game::{GameOptions, GameState, StratoGame},
+    player::{EndAction, StartAction},
+    rules::Rules,
+    snapshot::{GameSnapshot, LoadError},
+};
+
+use common::game_in_progress;
+
+#[test]
+fn a_game_can_be_saved_and_loaded_mid_turn() {
+    let (game, _, player_2_id) = game_in_progress(3);
+
+    let snapshot = game.save();
+    assert_eq!(snapshot.version(), GameSnapshot::VERSION);
+
+    let loaded = StratoGame::load(snapshot).unwrap();
+    assert_eq!(loaded.state, game.state);
+    assert_eq!(loaded.context, game.context);
+    assert_eq!(loaded.seed(), game.seed());
+    assert_eq!(
+        loaded.get_player(&player_2_id).unwrap().holding(),
+        game.get_player(&player_2_id).unwrap().holding()
+    );
+}
+
+#[test]
+fn a_loaded_game_carries_on_like_the_original() {
+    let (mut game, player_1_id, player_2_id) = game_in_progress(3);
+    let mut loaded = StratoGame::load(game.save()).unwrap();
+
+    for game in [&mut game, &mut loaded] {
+        game.end_player_turn(&player_2_id, EndAction::Swap { row: 0, column: 0 })
+            .unwrap();
+        game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+            .unwrap();
+        game.end_player_turn(&player_1_id, EndAction::Flip { row: 2, column: 2 })
+            .unwrap();
+    }
+
+    assert_eq!(loaded.state, GameState::Active);
+    assert_eq!(loaded.context, game.context);
+}
+
+#[test]
+fn extra_cards_are_rejected() {
+    let (mut game, _, _) = game_in_progress(3);
+    game.context.deck = Deck::default();
+
+    let result = StratoGame::load(game.save());
+    assert!(matches!(
+        result.unwrap_err(),
+        LoadError::TooManyCards { allowed: 10, .. }
+    ));
+}
+
+#[test]
+fn missing_players_are_rejected() {
+    let (mut game, _, _) = game_in_progress(3);
+    game.context.current_player_idx = Some(5);
+
+    let result = StratoGame::load(game.save());
+    assert_eq!(result.unwrap_err(), LoadError::CurrentPlayerDoesntExist);
+}
+
+#[test]
+fn a_card_held_out_of_turn_is_rejected() {
+    let (mut game, _, _) = game_in_progress(3);
+    game.context.current_player_idx = Some(0);
+
+    let result = StratoGame::load(game.save());
+    assert_eq!(result.unwrap_err(), LoadError::CardHeldOutOfTurn);
+}
+
+#[test]
+fn the_last_round_needs_a_last_player() {
+    let (mut game, _, _) = game_in_progress(3);
+    game.state = GameState::LastRound;
+
+    let result = StratoGame::load(game.save());
+    assert_eq!(result.unwrap_err(), LoadError::MissingLastPlayer);
+}
+
+#[test]
+fn rounds_and_turns_are_saved() {
+    let (game, _, _) = game_in_progress(3);
+    let loaded = StratoGame::load(game.save()).unwrap();
+
+    assert_eq!((loaded.round(), loaded.turn()), (1, 2));
+    assert_eq!(loaded.turns(), game.turns());
+}
+
+#[test]
+fn a_big_table_can_be_saved_and_loaded() {
+    let mut game = StratoGame::new();
+    game.set_max_players(10).unwrap();
+    for n in 0..10 {
+        game.add_player(format!("Player {}", n + 1)).unwrap();
+    }
+    game.start().unwrap();
+
+    let loaded = StratoGame::load(game.save()).unwrap();
+    assert_eq!(loaded.context, game.context);
+    assert_eq!(loaded.max_players(), 10);
+}
+
+#[test]
+fn cards_are_checked_against_the_deck_that_was_used() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    game.start_with_options(GameOptions {
+        first_player_idx: Some(0),
+        rules: Rules::official(),
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(StratoGame::load(game.save()).is_ok());
+
+    let negative_twos = DeckComposition::empty().with(CardValue::NegativeTwo, 10);
+    game.context.deck = Deck::with_composition(&negative_twos, 1);
+    let result = StratoGame::load(game.save());
+    assert!(matches!(
+        result.unwrap_err(),
+        LoadError::TooManyCards {
+            value: -2,
+            allowed: 5,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn spreads_of_the_wrong_size_are_rejected() {
+    let (mut game, _, _) = game_in_progress(3);
+    game.context.players[1].spread = PlayerSpread::with_size(SpreadSize::new(3, 3));
+
+    let result = StratoGame::load(game.save());
+    assert_eq!(result.unwrap_err(), LoadError::WrongSpreadSize);
+}
@@ -0,0 +1,160 @@
+use std::sync::{Arc, Mutex};
+
+use strato::{
+    self,
+    game::{FirstPlayerTieBreak, GameEvent, GameOptions, GameStartupError, Seating, StratoGame},
+};
+
+fn add_players(game: &mut StratoGame) -> Vec<String> {
+    ["Parker", "Trevor", "Cassie", "James"]
+        .into_iter()
+        .map(|name| game.add_player(name).unwrap())
+        .collect()
+}
+
+fn seat_ids(game: &StratoGame) -> Vec<String> {
+    game.list_players().iter().map(|p| p.id()).collect()
+}
+
+#[test]
+fn players_are_seated_in_join_order_by_default() {
+    let mut game = StratoGame::new();
+    let player_ids = add_players(&mut game);
+    game.start().unwrap();
+
+    assert_eq!(seat_ids(&game), player_ids);
+}
+
+#[test]
+fn the_host_can_choose_the_seating_order() {
+    let mut game = StratoGame::new();
+    let player_ids = add_players(&mut game);
+    let order = vec![
+        player_ids[2].clone(),
+        player_ids[0].clone(),
+        player_ids[3].clone(),
+        player_ids[1].clone(),
+    ];
+    game.start_with_options(GameOptions {
+        first_player_idx: Some(0),
+        seating: Seating::Explicit(order.clone()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(seat_ids(&game), order);
+    assert_eq!(game.context.current_player_idx, Some(0));
+    assert_eq!(game.list_players()[0].name(), "Cassie");
+}
+
+#[test]
+fn the_chosen_order_must_list_every_player_once() {
+    let mut game = StratoGame::new();
+    let player_ids = add_players(&mut game);
+
+    for order in [
+        player_ids[..3].to_vec(),
+        vec![player_ids[0].clone(); 4],
+        vec![
+            player_ids[0].clone(),
+            player_ids[1].clone(),
+            player_ids[2].clone(),
+            "nobody".to_string(),
+        ],
+    ] {
+        let result = game.start_with_options(GameOptions {
+            seating: Seating::Explicit(order),
+            ..Default::default()
+        });
+        assert_eq!(result, Err(GameStartupError::InvalidSeating));
+    }
+    assert_eq!(seat_ids(&game), player_ids);
+}
+
+#[test]
+fn random_seating_is_reproducible_with_a_seed() {
+    let seatings = (0..2)
+        .map(|_| {
+            let mut game = StratoGame::with_seed(5);
+            add_players(&mut game);
+            game.start_with_options(GameOptions {
+                seating: Seating::Random,
+                ..Default::default()
+            })
+            .unwrap();
+            seat_ids(&game)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(seatings[0], seatings[1]);
+
+    let mut sorted = seatings[0].clone();
+    sorted.sort();
+    let mut game = StratoGame::with_seed(5);
+    let mut player_ids = add_players(&mut game);
+    player_ids.sort();
+    assert_eq!(sorted, player_ids);
+}
+
+#[test]
+fn random_seating_survives_a_replay() {
+    let mut game = StratoGame::new();
+    add_players(&mut game);
+    game.start_with_options(GameOptions {
+        seating: Seating::Random,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let replayed = StratoGame::replay(game.seed(), game.actions()).unwrap();
+    assert_eq!(seat_ids(&replayed), seat_ids(&game));
+}
+
+#[test]
+fn the_seating_order_is_reported() {
+    let mut game = StratoGame::new();
+    add_players(&mut game);
+    let events = Arc::new(Mutex::new(vec![]));
+    game.subscribe({
+        let events = events.clone();
+        move |e| events.lock().unwrap().push(e)
+    });
+    game.start_with_options(GameOptions {
+        seating: Seating::Random,
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert!(events.lock().unwrap().contains(&GameEvent::PlayersSeated {
+        player_ids: seat_ids(&game)
+    }));
+}
+
+#[test]
+fn players_cant_be_picked_by_seat_when_seating_is_random() {
+    let mut game = StratoGame::new();
+    add_players(&mut game);
+
+    for options in [
+        GameOptions {
+            first_player_idx: Some(0),
+            seating: Seating::Random,
+            ..Default::default()
+        },
+        GameOptions {
+            first_player_tie_break: FirstPlayerTieBreak::Youngest(vec![3, 2, 1, 0]),
+            seating: Seating::Random,
+            ..Default::default()
+        },
+    ] {
+        let result = game.start_with_options(options);
+        assert_eq!(result, Err(GameStartupError::SeatsNotKnownYet));
+    }
+
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Random,
+        seating: Seating::Random,
+        ..Default::default()
+    })
+    .unwrap();
+}
@@ -0,0 +1,205 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use serde::{de::DeserializeOwned, Serialize};
+use strato::{
+    self,
+    card::{CardValue, Deck, DeckComposition, DiscardPile, PlayerSpread},
+    command::PlayerCommand,
+    game::{FirstPlayerTieBreak, GameOptions, GameState, Seating, StratoGame},
+    player::{EndAction, Player, StartAction},
+    rules::Rules,
+    snapshot::{GameSnapshot, LoadError},
+};
+
+use common::game_in_progress;
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).expect("Couldn't serialize");
+    serde_json::from_str(&json).expect("Couldn't deserialize")
+}
+
+#[test]
+fn card_values_serialize_as_numbers() {
+    assert_eq!(
+        serde_json::to_string(&CardValue::NegativeTwo).unwrap(),
+        "-2"
+    );
+    assert_eq!(serde_json::to_string(&CardValue::Twelve).unwrap(), "12");
+    assert_eq!(
+        serde_json::from_str::<CardValue>("7").unwrap(),
+        CardValue::Seven
+    );
+}
+
+#[test]
+fn out_of_range_card_values_are_rejected() {
+    assert!(serde_json::from_str::<CardValue>("13").is_err());
+    assert!(serde_json::from_str::<CardValue>("-3").is_err());
+}
+
+#[test]
+fn decks_and_piles_round_trip() {
+    let mut deck = Deck::default();
+    deck.shuffle();
+    assert_eq!(round_trip(&deck), deck);
+
+    let mut discard_pile = DiscardPile::new();
+    discard_pile.put(deck.draw().unwrap());
+    assert_eq!(round_trip(&discard_pile), discard_pile);
+}
+
+#[test]
+fn deck_compositions_round_trip() {
+    let composition = DeckComposition::official().with(CardValue::Twelve, 0);
+    assert_eq!(round_trip(&composition), composition);
+}
+
+#[test]
+fn commands_round_trip() {
+    let commands = [
+        PlayerCommand::FlipToDetermineFirstPlayer { row: 1, column: 3 },
+        PlayerCommand::StartTurn {
+            action: StartAction::TakeFromDiscardPile,
+        },
+        PlayerCommand::EndTurn {
+            action: EndAction::Swap { row: 2, column: 0 },
+        },
+        PlayerCommand::Forfeit,
+    ];
+    for command in commands {
+        assert_eq!(round_trip(&command), command);
+    }
+}
+
+#[test]
+fn options_round_trip() {
+    let options = GameOptions {
+        first_player_idx: Some(1),
+        seating: Seating::Explicit(vec!["b".to_string(), "a".to_string()]),
+        ..Default::default()
+    };
+    assert_eq!(round_trip(&options), options);
+}
+
+#[test]
+fn rules_round_trip() {
+    assert_eq!(round_trip(&Rules::official()), Rules::official());
+}
+
+#[test]
+fn spreads_and_players_round_trip() {
+    let (game, _, _) = game_in_progress(8);
+
+    let player: &Player = &game.list_players()[1];
+    assert!(player.holding().is_some());
+    assert_eq!(&round_trip(player), player);
+
+    let spread: &PlayerSpread = &game.list_players()[0].spread;
+    assert_eq!(&round_trip(spread), spread);
+}
+
+#[test]
+fn game_state_and_context_round_trip() {
+    let (game, _, _) = game_in_progress(8);
+
+    assert_eq!(round_trip(&game.state), GameState::Active);
+    assert_eq!(round_trip(&game.context), game.context);
+}
+
+#[test]
+fn actions_round_trip() {
+    assert_eq!(
+        round_trip(&StartAction::TakeFromDiscardPile),
+        StartAction::TakeFromDiscardPile
+    );
+    assert_eq!(
+        round_trip(&EndAction::Swap { row: 2, column: 3 }),
+        EndAction::Swap { row: 2, column: 3 }
+    );
+}
+
+#[test]
+fn saved_games_round_trip() {
+    let (game, _, _) = game_in_progress(8);
+    let snapshot = round_trip(&game.save());
+
+    let loaded = StratoGame::load(snapshot).unwrap();
+    assert_eq!(loaded.context, game.context);
+    assert_eq!(loaded.actions(), game.actions());
+}
+
+#[test]
+fn action_logs_round_trip_and_replay() {
+    let (game, _, _) = game_in_progress(8);
+    let actions = round_trip(&game.actions().to_vec());
+
+    let replayed = StratoGame::replay(game.seed(), &actions).unwrap();
+    assert_eq!(replayed.context, game.context);
+}
+
+/// Save the game as JSON, replace part of it and load it back.
+fn load_edited(game: &StratoGame, from: &str, to: &str) -> Result<(), LoadError> {
+    let json = serde_json::to_string(&game.save()).unwrap();
+    assert!(json.contains(from));
+    let snapshot: GameSnapshot = serde_json::from_str(&json.replacen(from, to, 1)).unwrap();
+    StratoGame::load(snapshot).map(|_| ())
+}
+
+#[test]
+fn tie_breaks_must_refer_to_players_in_the_game() {
+    let mut game = StratoGame::new();
+    for name in ["Parker", "Trevor", "Lexi"] {
+        game.add_player(name).unwrap();
+    }
+    game.start_with_options(GameOptions {
+        first_player_tie_break: FirstPlayerTieBreak::Youngest(vec![2, 0, 1]),
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(StratoGame::load(game.save()).is_ok());
+
+    let result = load_edited(&game, "\"tied_for_first\":[]", "\"tied_for_first\":[7]");
+    assert_eq!(result, Err(LoadError::TiedPlayerDoesntExist));
+
+    let result = load_edited(&game, "\"Youngest\":[2,0,1]", "\"Youngest\":[2,0,5]");
+    assert_eq!(result, Err(LoadError::YoungestPlayerDoesntExist));
+}
+
+#[test]
+fn only_a_game_determining_its_first_player_can_have_a_tie() {
+    let (game, _, _) = game_in_progress(8);
+
+    let result = load_edited(&game, "\"tied_for_first\":[]", "\"tied_for_first\":[0,1]");
+    assert_eq!(result, Err(LoadError::UnexpectedTieForFirst));
+}
+
+#[test]
+fn a_held_card_without_its_source_is_rejected() {
+    let (game, _, _) = game_in_progress(8);
+
+    let result = load_edited(
+        &game,
+        "\"held_from\":\"DrawFromDeck\"",
+        "\"held_from\":null",
+    );
+    assert_eq!(result.unwrap_err(), LoadError::HeldCardWithoutSource);
+}
+
+#[test]
+fn saved_games_from_another_version_are_rejected() {
+    let (game, _, _) = game_in_progress(8);
+    let json = serde_json::to_string(&game.save()).unwrap();
+    let json = json.replacen(
+        &format!("\"version\":{}", GameSnapshot::VERSION),
+        &format!("\"version\":{}", GameSnapshot::VERSION + 1),
+        1,
+    );
+
+    let snapshot: GameSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        StratoGame::load(snapshot).unwrap_err(),
+        LoadError::UnsupportedVersion(GameSnapshot::VERSION + 1)
+    );
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use strato::{
+    self,
+    game::{GameOptions, StratoGame, TurnRecord},
+    player::{EndAction, StartAction},
+};
+
+use common::start_game;
+
+fn start_game_with_three(first_player_idx: usize) -> (StratoGame<'static>, [String; 3]) {
+    start_game(
+        ["Parker", "Trevor", "Cassie"],
+        GameOptions {
+            first_player_idx: Some(first_player_idx),
+            allow_undo: true,
+            ..Default::default()
+        },
+    )
+}
+
+fn play_turn(game: &mut StratoGame, player_id: &str) {
+    game.start_player_turn(player_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(player_id, EndAction::Swap { row: 0, column: 0 })
+        .unwrap();
+}
+
+#[test]
+fn rounds_and_turns_start_counting_with_the_first_turn() {
+    let mut game = StratoGame::new();
+    game.add_player("Parker").unwrap();
+    game.add_player("Trevor").unwrap();
+    assert_eq!((game.round(), game.turn()), (0, 0));
+
+    game.start().unwrap();
+    assert_eq!((game.round(), game.turn()), (0, 0));
+
+    let (game, _) = start_game_with_three(0);
+    assert_eq!((game.round(), game.turn()), (1, 1));
+}
+
+#[test]
+fn a_round_ends_when_play_is_back_to_the_starting_player() {
+    let (mut game, player_ids) = start_game_with_three(1);
+
+    play_turn(&mut game, &player_ids[1]);
+    play_turn(&mut game, &player_ids[2]);
+    assert_eq!((game.round(), game.turn()), (1, 3));
+
+    play_turn(&mut game, &player_ids[0]);
+    assert_eq!((game.round(), game.turn()), (2, 4));
+    assert_eq!(game.context.current_player_idx, Some(1));
+}
+
+#[test]
+fn every_turn_is_recorded() {
+    let (mut game, player_ids) = start_game_with_three(0);
+    let discard_pile_top = game
+        .context
+        .discard_pile
+        .top()
+        .unwrap()
+        .get_value()
+        .unwrap();
+
+    game.start_player_turn(&player_ids[0], StartAction::TakeFromDiscardPile)
+        .unwrap();
+    game.end_player_turn(&player_ids[0], EndAction::Swap { row: 2, column: 1 })
+        .unwrap();
+    play_turn(&mut game, &player_ids[1]);
+
+    let turns = game.turns();
+    assert_eq!(turns.len(), 2);
+    assert_eq!(
+        turns[0],
+        TurnRecord {
+            turn: 1,
+            round: 1,
+            player_id: player_ids[0].clone(),
+            start: StartAction::TakeFromDiscardPile,
+            end: EndAction::Swap { row: 2, column: 1 },
+            card: discard_pile_top,
+        }
+    );
+    assert_eq!(turns[1].turn, 2);
+    assert_eq!(turns[1].player_id, player_ids[1]);
+}
+
+#[test]
+fn undoing_a_turn_takes_back_its_record() {
+    let (mut game, player_ids) = start_game_with_three(0);
+
+    play_turn(&mut game, &player_ids[0]);
+    game.undo().unwrap();
+
+    assert_eq!((game.round(), game.turn()), (1, 1));
+    assert!(game.turns().is_empty());
+}
+
+#[test]
+fn rounds_keep_counting_when_the_starting_player_leaves() {
+    let (mut game, player_ids) = start_game_with_three(0);
+
+    play_turn(&mut game, &player_ids[0]);
+    play_turn(&mut game, &player_ids[1]);
+    game.forfeit(&player_ids[0]).unwrap();
+    play_turn(&mut game, &player_ids[2]);
+    assert_eq!((game.round(), game.turn()), (2, 4));
+    assert_eq!(game.context.current_player_idx, Some(0));
+}
+
+#[test]
+fn a_round_carries_on_if_the_starting_player_leaves_on_their_turn() {
+    let (mut game, player_ids) = start_game_with_three(0);
+
+    game.forfeit(&player_ids[0]).unwrap();
+    assert_eq!((game.round(), game.turn()), (1, 2));
+
+    play_turn(&mut game, &player_ids[1]);
+    play_turn(&mut game, &player_ids[2]);
+    assert_eq!((game.round(), game.turn()), (2, 4));
+}
+
+#[test]
+fn views_show_the_round_turn_and_last_turn() {
+    let (mut game, player_ids) = start_game_with_three(0);
+    play_turn(&mut game, &player_ids[0]);
+
+    let view = game.view_for(&player_ids[1]).unwrap();
+    assert_eq!((view.round, view.turn), (1, 2));
+    assert_eq!(view.last_turn.as_ref(), game.turns().last());
+    assert_eq!(game.spectator_view().last_turn, view.last_turn);
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use strato::{
+    self,
+    game::{GameOptions, GameState, UndoError},
+    player::{EndAction, StartAction},
+};
+
+use common::{rig_card, start_two_player_game};
+
+#[test]
+fn undo_must_be_allowed_in_the_options() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    assert_eq!(game.undo(), Err(UndoError::NotAllowed));
+}
+
+#[test]
+fn starting_a_turn_can_be_undone() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions {
+        allow_undo: true,
+        ..Default::default()
+    });
+    let before = game.context.clone();
+
+    game.start_player_turn(&player_1_id, StartAction::TakeFromDiscardPile)
+        .unwrap();
+    game.undo().unwrap();
+
+    assert_eq!(game.context, before);
+    assert!(game.get_player(&player_1_id).unwrap().holding().is_none());
+    assert_eq!(game.actions().len(), 3);
+}
+
+#[test]
+fn ending_a_turn_can_be_undone_and_played_differently() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions {
+        allow_undo: true,
+        ..Default::default()
+    });
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    let holding = game.context.clone();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 1 })
+        .unwrap();
+
+    game.undo().unwrap();
+    assert_eq!(game.context, holding);
+    assert_eq!(game.context.current_player_idx, Some(0));
+
+    game.end_player_turn(&player_1_id, EndAction::Swap { row: 0, column: 2 })
+        .unwrap();
+    assert_eq!(game.context.current_player_idx, Some(1));
+}
+
+#[test]
+fn undo_is_refused_once_the_next_player_has_acted() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions {
+        allow_undo: true,
+        ..Default::default()
+    });
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 0, column: 1 })
+        .unwrap();
+    game.start_player_turn(&player_2_id, StartAction::DrawFromDeck)
+        .unwrap();
+
+    game.undo().unwrap();
+    assert_eq!(game.context.current_player_idx, Some(1));
+    assert_eq!(game.undo(), Err(UndoError::NothingToUndo));
+}
+
+#[test]
+fn undo_puts_back_a_cleared_column() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions {
+        allow_undo: true,
+        ..Default::default()
+    });
+    for row in 0..3 {
+        rig_card(&mut game, 0, row, 0, 5);
+    }
+    let spread = &mut game.context.players[0].spread;
+    spread.flip_at(0, 0).unwrap();
+    spread.flip_at(1, 0).unwrap();
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    let discard_pile = game.context.discard_pile.clone();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 2, column: 0 })
+        .unwrap();
+    assert_eq!(game.context.players[0].spread.active_columns(), 3);
+
+    game.undo().unwrap();
+    assert_eq!(game.context.players[0].spread.active_columns(), 4);
+    assert_eq!(game.context.discard_pile, discard_pile);
+}
+
+#[test]
+fn undo_reverts_the_last_round() {
+    let (mut game, player_1_id, _) = start_two_player_game(GameOptions {
+        allow_undo: true,
+        ..Default::default()
+    });
+    let spread = &mut game.context.players[0].spread;
+    for row in 0..3 {
+        for column in 0..4 {
+            if (row, column) != (2, 3) {
+                spread.flip_at(row, column).unwrap();
+            }
+        }
+    }
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 2, column: 3 })
+        .unwrap();
+    assert_eq!(game.state, GameState::LastRound);
+    assert_eq!(game.finisher_idx(), Some(0));
+
+    game.undo().unwrap();
+    assert_eq!(game.state, GameState::Active);
+    assert_eq!(game.finisher_idx(), None);
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use strato::{
+    self,
+    card::Spot,
+    game::{GameOptions, GameState},
+    player::{EndAction, StartAction},
+};
+
+use common::start_two_player_game;
+
+fn face_up_count(spread: &[Vec<Spot>]) -> usize {
+    spread
+        .iter()
+        .flatten()
+        .filter(|spot| matches!(spot, Spot::FaceUp(_)))
+        .count()
+}
+
+#[test]
+fn a_player_sees_their_own_turn_and_the_table() {
+    let (game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    let view = game.view_for(&player_1_id).unwrap();
+    assert_eq!(view.state, GameState::Active);
+    assert_eq!(view.player_id, player_1_id);
+    assert!(view.is_your_turn);
+    assert_eq!(view.current_player_id, Some(player_1_id));
+    assert_eq!(view.deck_size, game.context.deck.size());
+    assert!(view.discard_pile_top.is_some());
+    assert_eq!(view.opponents.len(), 1);
+    assert_eq!(view.opponents[0].player_id, player_2_id);
+    assert_eq!(view.opponents[0].name, "Trevor");
+
+    let other_view = game.view_for(&player_2_id).unwrap();
+    assert!(!other_view.is_your_turn);
+}
+
+#[test]
+fn hidden_cards_stay_hidden() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    game.end_player_turn(&player_1_id, EndAction::Flip { row: 1, column: 2 })
+        .unwrap();
+
+    let view = game.view_for(&player_2_id).unwrap();
+    assert_eq!(face_up_count(&view.spread), 0);
+    assert_eq!(face_up_count(&view.opponents[0].spread), 1);
+    assert!(matches!(view.opponents[0].spread[1][2], Spot::FaceUp(_)));
+    assert_eq!(view.opponents[0].spread[0][0], Spot::FaceDown);
+}
+
+#[test]
+fn only_the_holder_sees_the_held_card() {
+    let (mut game, player_1_id, player_2_id) = start_two_player_game(GameOptions::default());
+
+    game.start_player_turn(&player_1_id, StartAction::DrawFromDeck)
+        .unwrap();
+    let held_value = game
+        .get_player(&player_1_id)
+        .unwrap()
+        .holding()
+        .unwrap()
+        .get_value();
+
+    let view = game.view_for(&player_1_id).unwrap();
+    assert_eq!(view.holding, held_value);
+
+    let other_view = game.view_for(&player_2_id).unwrap();
+    assert_eq!(other_view.holding, None);
+    assert!(other_view.opponents[0].is_holding);
+
+    let spectator_view = game.spectator_view();
+    assert!(spectator_view.players[0].is_holding);
+    assert!(!spectator_view.players[1].is_holding);
+}
+
+#[test]
+fn spectators_see_every_player_without_hidden_cards() {
+    let (game, player_1_id, _) = start_two_player_game(GameOptions::default());
+
+    let view = game.spectator_view();
+    assert_eq!(view.players.len(), 2);
+    assert_eq!(view.current_player_id, Some(player_1_id));
+    for player in view.players {
+        assert_eq!(face_up_count(&player.spread), 0);
+    }
+}
+
+#[test]
+fn there_is_no_view_for_an_unknown_player() {
+    let (game, _, _) = start_two_player_game(GameOptions::default());
+    assert!(game.view_for("nobody").is_none());
+}